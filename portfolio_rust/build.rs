@@ -0,0 +1,109 @@
+//! Bakes build-time data into the binary so the terminal works offline.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    github_snapshot(Path::new(&out_dir));
+    build_info(Path::new(&out_dir));
+}
+
+/// Output of `git args...`, or `None` outside a checkout or without git
+/// (e.g. a source tarball in Docker).
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    let text = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !text.trim().is_empty()).then(|| text.trim().to_string())
+}
+
+/// `YYYY-MM-DD HH:MM UTC` for seconds since the Unix epoch.
+fn utc_timestamp(secs: u64) -> String {
+    // Howard Hinnant's days-to-civil conversion.
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02} UTC", year, month, day, secs / 3600 % 24, secs / 60 % 60)
+}
+
+/// Version, commit, build time and compiler for `version`, `uname` and
+/// `neofetch`.
+fn build_info(out_dir: &Path) {
+    // HEAD moves on checkout; its reflog on every commit.
+    for file in ["HEAD", "logs/HEAD"] {
+        if let Some(path) = git(&["rev-parse", "--git-path", file]) {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+    // Any source edit rebuilds the binary, so BUILT_AT follows it.
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    let hash = git(&["rev-parse", "--short", "HEAD"]).unwrap_or_else(|| "unknown".to_string());
+    let date = git(&["log", "-1", "--format=%cs"]).unwrap_or_else(|| "unknown".to_string());
+    // Reproducible builds pin the clock with SOURCE_DATE_EPOCH.
+    let built = env::var("SOURCE_DATE_EPOCH").ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default());
+    let rustc = env::var("RUSTC").ok()
+        .and_then(|rustc| Command::new(rustc).arg("--version").output().ok())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let constants = [
+        ("VERSION", env::var("CARGO_PKG_VERSION").unwrap_or_default()),
+        ("GIT_HASH", hash),
+        ("GIT_DATE", date),
+        ("BUILT_AT", utc_timestamp(built)),
+        ("RUSTC_VERSION", rustc),
+        ("TARGET", env::var("TARGET").unwrap_or_default()),
+    ];
+    let code: String = constants.iter()
+        .map(|(name, value)| format!("pub static {}: &str = {:?};\n", name, value))
+        .collect();
+    fs::write(out_dir.join("build_info.rs"), code).expect("writing build_info.rs");
+}
+
+/// Turns `data/github.json` into a static `REPOS` table for `gh`.
+fn github_snapshot(out_dir: &Path) {
+    let source = "data/github.json";
+    println!("cargo:rerun-if-changed={}", source);
+
+    let json = fs::read_to_string(source).unwrap_or_else(|e| panic!("reading {}: {}", source, e));
+    let snapshot: Value = serde_json::from_str(&json).unwrap_or_else(|e| panic!("parsing {}: {}", source, e));
+    let text = |v: &Value, key: &str| v.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+    // Repos may be trimmed by hand or pasted straight from the GitHub API.
+    let either = |v: &Value, key: &str, api_key: &str| v.get(key).or_else(|| v.get(api_key)).cloned().unwrap_or_default();
+
+    let mut code = format!(
+        "pub static SNAPSHOT_USER: &str = {:?};\npub static SNAPSHOT_DATE: &str = {:?};\npub static REPOS: &[Repo] = &[\n",
+        text(&snapshot, "user"),
+        text(&snapshot, "generated_at"),
+    );
+    for repo in snapshot.get("repos").and_then(Value::as_array).into_iter().flatten() {
+        code.push_str(&format!(
+            "    Repo {{ name: {:?}, description: {:?}, language: {:?}, stars: {}, url: {:?}, readme: {:?} }},\n",
+            text(repo, "name"),
+            text(repo, "description"),
+            text(repo, "language"),
+            either(repo, "stars", "stargazers_count").as_u64().unwrap_or_default(),
+            either(repo, "url", "html_url").as_str().unwrap_or_default(),
+            text(repo, "readme"),
+        ));
+    }
+    code.push_str("];\n");
+
+    fs::write(out_dir.join("github_snapshot.rs"), code).expect("writing github_snapshot.rs");
+}
@@ -0,0 +1,85 @@
+# English messages. Every message id here must also exist in hi.ftl;
+# missing ones fall back to English.
+
+## Welcome
+
+welcome-title = Terminal Portfolio - Ashish Kumar
+welcome-hint = 👋 Welcome! Type 'help' to see available commands
+welcome-tour = New here? Type 'tour' for a guided walkthrough
+
+## Errors
+
+not-found = ❌ Command not found: '{ $command }'
+not-found-hint = Type 'help' for available commands
+
+## help
+
+help-title = AVAILABLE COMMANDS
+help-help = Show this help message
+help-tour = Guided walkthrough
+help-whoami = About me
+help-skills = Technical skills (--category, --min, --sort)
+help-education = Educational background
+help-projects = List projects (--tag, --since, search)
+help-cat = View project details or any file
+help-files = List or change directories (ls ~/projects)
+help-file-ops = Create, delete, move & copy your own files
+help-redirect = Save output to a file (>> appends)
+help-sh = Run a shell script (try: sh demo.sh)
+help-alias = Define a shortcut; put them in ~/.portfoliorc
+help-timeline = Education, projects & certifications by date
+help-certifications = Certifications & courses
+help-contact = Contact information
+help-mail = Send me a message (also: contact --send)
+help-resume = Full resume (--md, --txt, --pdf)
+help-search = Search everything (e.g. search wireshark)
+help-open = Open linkedin, github, email or resume (or pick one)
+help-gh = Browse GitHub repositories
+help-neofetch = Browser, session and build info
+help-clear = Clear terminal
+help-lang = Change the language (lang set hi)
+help-typing = Typing animation for output
+help-version = Version, commit, build time and compiler
+help-uname = Kernel, release and machine, like uname
+help-nano = Edit a file; your files are saved in your browser
+help-theme = Color theme (cobalt, matrix, solarized)
+help-curl = Fetch a URL (Ctrl+C cancels)
+help-sleep = Wait (Ctrl+C cancels)
+help-background = Run in the background
+help-jobs = Manage background jobs (e.g. fg %1)
+help-record = Record this session (start/stop/save)
+help-replay = Play back the last recording
+help-navigation = Navigation:
+help-key-history = Command history
+help-key-tab = Auto-completion
+help-key-mux = Tabs & split panes
+
+## whoami
+
+whoami-title = ABOUT ME
+whoami-name = Name
+whoami-role = Role
+whoami-role-value = Cybersecurity Enthusiast & AI/ML Student
+whoami-location = Location
+whoami-education = Education
+whoami-cgpa = CGPA
+whoami-year = Year
+whoami-year-value = Third Year (Expected { $date })
+whoami-summary =
+    Third-year B.Tech student specializing in AI & ML,
+    currently exploring cybersecurity fundamentals with
+    hands-on experience in Linux, Python scripting, and
+    security tools like Wireshark and Kali Linux.
+whoami-focus = Focus
+whoami-focus-value = Network Security, Threat Detection
+whoami-seeking = Seeking
+whoami-seeking-value = Cybersecurity Internship
+
+## lang
+
+lang-current = Language: { $name } ({ $code })
+lang-available = Available: { $list }
+lang-changed = Language set to { $name }.
+lang-unknown = Unknown language '{ $code }'.
+lang-usage = Usage: lang [list | set CODE]
+lang-note = Other commands show portfolio content in English.
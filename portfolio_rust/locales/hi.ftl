@@ -0,0 +1,84 @@
+# हिन्दी संदेश। जो संदेश यहाँ नहीं हैं वे अंग्रेज़ी में दिखते हैं।
+
+## Welcome
+
+welcome-title = टर्मिनल पोर्टफ़ोलियो - आशीष कुमार
+welcome-hint = 👋 स्वागत है! उपलब्ध कमांड देखने के लिए 'help' टाइप करें
+welcome-tour = पहली बार आए हैं? गाइडेड टूर के लिए 'tour' टाइप करें
+
+## Errors
+
+not-found = ❌ कमांड नहीं मिली: '{ $command }'
+not-found-hint = उपलब्ध कमांड के लिए 'help' टाइप करें
+
+## help
+
+help-title = उपलब्ध कमांड
+help-help = यह सहायता संदेश दिखाएँ
+help-tour = गाइडेड टूर
+help-whoami = मेरे बारे में
+help-skills = तकनीकी कौशल (--category, --min, --sort)
+help-education = शैक्षणिक पृष्ठभूमि
+help-projects = प्रोजेक्ट की सूची (--tag, --since, search)
+help-cat = प्रोजेक्ट या किसी फ़ाइल का विवरण देखें
+help-files = डायरेक्टरी देखें या बदलें (ls ~/projects)
+help-file-ops = अपनी फ़ाइलें बनाएँ, हटाएँ, ले जाएँ और कॉपी करें
+help-redirect = आउटपुट फ़ाइल में सहेजें (>> जोड़ता है)
+help-sh = शेल स्क्रिप्ट चलाएँ (आज़माएँ: sh demo.sh)
+help-alias = शॉर्टकट बनाएँ; इन्हें ~/.portfoliorc में रखें
+help-timeline = तारीख़ के अनुसार शिक्षा, प्रोजेक्ट और प्रमाणपत्र
+help-certifications = प्रमाणपत्र और कोर्स
+help-contact = संपर्क जानकारी
+help-mail = मुझे संदेश भेजें (या: contact --send)
+help-resume = पूरा रिज़्यूमे (--md, --txt, --pdf)
+help-search = सब कुछ खोजें (जैसे search wireshark)
+help-open = linkedin, github, email या resume खोलें
+help-gh = GitHub रिपॉज़िटरी देखें
+help-neofetch = ब्राउज़र, सत्र और बिल्ड की जानकारी
+help-clear = टर्मिनल साफ़ करें
+help-lang = भाषा बदलें (lang set en)
+help-typing = आउटपुट के लिए टाइपिंग एनिमेशन
+help-version = वर्शन, कमिट, बिल्ड समय और कंपाइलर
+help-uname = कर्नेल, रिलीज़ और मशीन, uname की तरह
+help-nano = फ़ाइल संपादित करें; आपकी फ़ाइलें आपके ब्राउज़र में सहेजी जाती हैं
+help-theme = रंग थीम (cobalt, matrix, solarized)
+help-curl = कोई URL लाएँ (Ctrl+C से रद्द करें)
+help-sleep = प्रतीक्षा करें (Ctrl+C से रद्द करें)
+help-background = बैकग्राउंड में चलाएँ
+help-jobs = बैकग्राउंड जॉब प्रबंधित करें (जैसे fg %1)
+help-record = यह सत्र रिकॉर्ड करें (start/stop/save)
+help-replay = पिछली रिकॉर्डिंग फिर से चलाएँ
+help-navigation = नेविगेशन:
+help-key-history = कमांड इतिहास
+help-key-tab = ऑटो-कम्प्लीशन
+help-key-mux = टैब और स्प्लिट पेन
+
+## whoami
+
+whoami-title = मेरे बारे में
+whoami-name = नाम
+whoami-role = भूमिका
+whoami-role-value = साइबर सुरक्षा उत्साही और AI/ML छात्र
+whoami-location = स्थान
+whoami-education = शिक्षा
+whoami-cgpa = CGPA
+whoami-year = वर्ष
+whoami-year-value = तृतीय वर्ष (अपेक्षित स्नातक: { $date })
+whoami-summary =
+    AI और ML में विशेषज्ञता वाला तृतीय वर्ष का B.Tech छात्र,
+    जो अभी साइबर सुरक्षा की बुनियादी बातें सीख रहा है और
+    Linux, Python स्क्रिप्टिंग तथा Wireshark और Kali Linux
+    जैसे सुरक्षा टूल्स का व्यावहारिक अनुभव रखता है।
+whoami-focus = फ़ोकस
+whoami-focus-value = नेटवर्क सुरक्षा, खतरे की पहचान
+whoami-seeking = तलाश
+whoami-seeking-value = साइबर सुरक्षा इंटर्नशिप
+
+## lang
+
+lang-current = भाषा: { $name } ({ $code })
+lang-available = उपलब्ध: { $list }
+lang-changed = भाषा { $name } पर सेट की गई।
+lang-unknown = अज्ञात भाषा '{ $code }'।
+lang-usage = उपयोग: lang [list | set CODE]
+lang-note = बाकी कमांड पोर्टफ़ोलियो की सामग्री अंग्रेज़ी में दिखाती हैं।
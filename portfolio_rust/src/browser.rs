@@ -0,0 +1,132 @@
+//! Thin wrappers over the browser APIs the commands lean on.
+
+use wasm_bindgen::prelude::*;
+use web_sys::{window, Blob, BlobPropertyBag, HtmlAnchorElement, PerformanceResourceTiming, Url, UrlSearchParams};
+
+/// Milliseconds since page load (monotonic).
+pub fn now() -> f64 {
+    window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now())
+        .unwrap_or_default()
+}
+
+/// The browser's user agent string.
+pub fn user_agent() -> String {
+    window().and_then(|w| w.navigator().user_agent().ok()).unwrap_or_default()
+}
+
+/// Inner width and height of the window in CSS pixels.
+pub fn viewport() -> (u32, u32) {
+    let size = |v: Result<JsValue, JsValue>| v.ok().and_then(|v| v.as_f64()).unwrap_or_default() as u32;
+    window()
+        .map(|w| (size(w.inner_width()), size(w.inner_height())))
+        .unwrap_or_default()
+}
+
+/// Decoded size in bytes of the first resource this page loaded whose URL
+/// ends with `suffix`, from the resource timing entries.
+pub fn resource_size(suffix: &str) -> Option<u64> {
+    let entries = window()?.performance()?.get_entries_by_type("resource");
+    entries.iter()
+        .filter_map(|entry| entry.dyn_into::<PerformanceResourceTiming>().ok())
+        .find(|entry| entry.name().split(['?', '#']).next().is_some_and(|url| url.ends_with(suffix)))
+        .map(|entry| entry.decoded_body_size() as u64)
+        .filter(|&size| size > 0)
+}
+
+/// The value of `name` in the page URL's query string; empty for a bare
+/// `?name`.
+pub fn query_param(name: &str) -> Option<String> {
+    let search = window()?.location().search().ok()?;
+    UrlSearchParams::new_with_str(&search).ok()?.get(name)
+}
+
+/// The value stored under `key` in `localStorage`, if any.
+pub fn load(key: &str) -> Option<String> {
+    window()?.local_storage().ok()??.get_item(key).ok()?
+}
+
+/// Stores `value` under `key` in `localStorage`. Fails when storage is
+/// disabled or full.
+pub fn store(key: &str, value: &str) -> Result<(), JsValue> {
+    window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .ok_or_else(|| JsValue::from_str("localStorage is unavailable"))?
+        .set_item(key, value)
+}
+
+/// Seconds since the Unix epoch.
+pub fn unix_time() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+/// Runs `f` once after `ms` milliseconds; returns the timer id.
+pub fn set_timeout(f: impl FnOnce() + 'static, ms: i32) -> i32 {
+    let callback = Closure::once_into_js(f);
+    window()
+        .and_then(|w| {
+            w.set_timeout_with_callback_and_timeout_and_arguments_0(callback.unchecked_ref(), ms).ok()
+        })
+        .unwrap_or_default()
+}
+
+pub fn clear_timeout(id: i32) {
+    if let Some(w) = window() {
+        w.clear_timeout_with_handle(id);
+    }
+}
+
+/// Offers `contents` to the visitor as a file download.
+pub fn download(filename: &str, mime: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+    download_url(filename, &url)?;
+    Url::revoke_object_url(&url)
+}
+
+/// Clicks a temporary `<a download>` pointing at `url`.
+pub fn download_url(filename: &str, url: &str) -> Result<(), JsValue> {
+    let document = window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let anchor: HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(url);
+    anchor.set_download(filename);
+    anchor.click();
+    Ok(())
+}
+
+/// Opens `url` in a new tab, or hands `mailto:` URLs to the mail client.
+pub fn open_url(url: &str) -> Result<(), JsValue> {
+    let window = window().ok_or_else(|| JsValue::from_str("no window"))?;
+    if url.starts_with("mailto:") {
+        return window.location().set_href(url);
+    }
+    match window.open_with_url_and_target(url, "_blank")? {
+        Some(_) => Ok(()),
+        None => Err(JsValue::from_str("blocked by the browser's popup blocker")),
+    }
+}
+
+/// Escapes text for safe inclusion in output HTML.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\'', "&#39;")
+        .replace('"', "&quot;")
+}
+
+/// Resolves after `ms` milliseconds.
+pub async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(w) = window() {
+            let _ = w.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
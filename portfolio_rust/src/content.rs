@@ -0,0 +1,357 @@
+//! Portfolio content as data, for commands that render it in more than one
+//! shape (resume exports, search, timeline, ...).
+
+pub struct Profile {
+    pub name: &'static str,
+    pub role: &'static str,
+    pub location: &'static str,
+    pub email: &'static str,
+    pub linkedin: &'static str,
+    pub github: &'static str,
+    pub summary: &'static str,
+    pub focus: &'static str,
+    pub seeking: &'static str,
+}
+
+pub struct Education {
+    pub degree: &'static str,
+    /// Short name for listings.
+    pub short_name: &'static str,
+    pub field: &'static str,
+    pub institution: &'static str,
+    pub location: &'static str,
+    /// Year of enrolment; the month is not on record.
+    pub started: u16,
+    /// (Expected) graduation.
+    pub graduates: YearMonth,
+    pub cgpa: &'static str,
+    pub coursework: &'static [&'static str],
+}
+
+impl Education {
+    /// "Expected May 2027", or "May 2027" once that date has passed.
+    pub fn graduation(&self) -> String {
+        if self.graduates > YearMonth::today() {
+            format!("Expected {}", self.graduates)
+        } else {
+            self.graduates.to_string()
+        }
+    }
+}
+
+/// A calendar month, the resolution all portfolio dates are kept at.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct YearMonth {
+    pub year: u16,
+    /// 1 to 12.
+    pub month: u8,
+}
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+impl YearMonth {
+    pub const fn new(year: u16, month: u8) -> YearMonth {
+        YearMonth { year, month }
+    }
+
+    /// The current month, from the browser's clock.
+    pub fn today() -> YearMonth {
+        let now = js_sys::Date::new_0();
+        YearMonth { year: now.get_full_year() as u16, month: now.get_month() as u8 + 1 }
+    }
+
+    /// Months since year 0, for arithmetic.
+    pub fn index(self) -> i32 {
+        self.year as i32 * 12 + self.month as i32 - 1
+    }
+
+    pub fn from_index(index: i32) -> YearMonth {
+        YearMonth { year: (index / 12) as u16, month: (index % 12 + 1) as u8 }
+    }
+
+    /// Parses `2024-03` or a bare year (`2024`, meaning January).
+    pub fn parse(text: &str) -> Option<YearMonth> {
+        let (year, month) = match text.split_once('-') {
+            Some((year, month)) => (year, month.parse().ok()?),
+            None => (text, 1),
+        };
+        let year = year.parse().ok().filter(|y| (1000..=9999).contains(y))?;
+        (1..=12).contains(&month).then_some(YearMonth { year, month })
+    }
+}
+
+impl std::fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", MONTHS[(self.month.clamp(1, 12) - 1) as usize], self.year)
+    }
+}
+
+/// An inclusive span of months.
+#[derive(Clone, Copy)]
+pub struct DateRange {
+    pub start: YearMonth,
+    pub end: YearMonth,
+}
+
+impl DateRange {
+    pub const fn new(start: YearMonth, end: YearMonth) -> DateRange {
+        DateRange { start, end }
+    }
+
+    pub fn overlaps_year(&self, year: u16) -> bool {
+        self.start.year <= year && year <= self.end.year
+    }
+}
+
+impl std::fmt::Display for DateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.start, self.end)
+    }
+}
+
+pub struct Project {
+    /// Name under `projects/`, as used by `cat projects/<id>`.
+    pub id: &'static str,
+    pub title: &'static str,
+    /// Short name for listings.
+    pub tagline: &'static str,
+    pub dates: DateRange,
+    pub summary: &'static str,
+    /// Heading for the highlights ("Key Features", "Learning Outcomes").
+    pub highlights_label: &'static str,
+    pub highlights: &'static [&'static str],
+    pub tech: &'static [&'static str],
+    /// Lowercase topics for `projects --tag`.
+    pub tags: &'static [&'static str],
+    /// `(label, url)` pairs; only links known to resolve.
+    pub links: &'static [(&'static str, &'static str)],
+}
+
+pub struct Certification {
+    pub name: &'static str,
+    pub issuer: &'static str,
+    /// Year completed; `None` while in progress.
+    pub year: Option<u16>,
+}
+
+impl Certification {
+    /// "2023" or "In Progress".
+    pub fn status(&self) -> String {
+        self.year.map_or_else(|| "In Progress".to_string(), |year| year.to_string())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SkillCategory {
+    Security,
+    Tools,
+    Programming,
+    Networking,
+    MachineLearning,
+}
+
+impl SkillCategory {
+    pub const ALL: [SkillCategory; 5] = [
+        SkillCategory::Security,
+        SkillCategory::Tools,
+        SkillCategory::Programming,
+        SkillCategory::Networking,
+        SkillCategory::MachineLearning,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SkillCategory::Security => "Cybersecurity",
+            SkillCategory::Tools => "Security Tools",
+            SkillCategory::Programming => "Programming",
+            SkillCategory::Networking => "Networking",
+            SkillCategory::MachineLearning => "Machine Learning",
+        }
+    }
+
+    /// Short name accepted by `skills --category`.
+    pub fn key(self) -> &'static str {
+        match self {
+            SkillCategory::Security => "security",
+            SkillCategory::Tools => "tools",
+            SkillCategory::Programming => "programming",
+            SkillCategory::Networking => "networking",
+            SkillCategory::MachineLearning => "ml",
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            SkillCategory::Security => "🔒",
+            SkillCategory::Tools => "🛠️",
+            SkillCategory::Programming => "💻",
+            SkillCategory::Networking => "🌐",
+            SkillCategory::MachineLearning => "🤖",
+        }
+    }
+}
+
+pub struct Skill {
+    pub name: &'static str,
+    pub category: SkillCategory,
+    /// Proficiency from 1 (aware of it) to 5 (expert); `None` where it is
+    /// not on record.
+    pub level: Option<u8>,
+    /// Years of use; `None` where it is not on record.
+    pub years: Option<u8>,
+}
+
+/// The highest [`Skill::level`].
+pub const MAX_LEVEL: u8 = 5;
+
+/// A level from a percentage on the main site's skill bars
+/// (`src/lib/portfolio-data.ts`), one level per 20%.
+const fn percent(value: u8) -> Option<u8> {
+    Some((value + 10) / 20)
+}
+
+pub const PROFILE: Profile = Profile {
+    name: "Ashish Kumar",
+    role: "Cybersecurity Enthusiast & AI/ML Student",
+    location: "Haryana, India",
+    email: "moryarudra009@gmail.com",
+    linkedin: "linkedin.com/in/rudramorye",
+    github: "github.com/ashishkumar0724",
+    summary: "Third-year B.Tech student specializing in AI & ML, currently exploring cybersecurity fundamentals with hands-on experience in Linux, Python scripting, and security tools like Wireshark and Kali Linux.",
+    focus: "Network Security, Threat Detection",
+    seeking: "Cybersecurity Internship",
+};
+
+pub const EDUCATION: Education = Education {
+    degree: "Bachelor of Technology (B.Tech)",
+    short_name: "B.Tech CSE (AI & ML)",
+    field: "Computer Science & Engineering with Specialization in Artificial Intelligence & Machine Learning",
+    institution: "Guru Gobind Singh Indraprastha University",
+    location: "New Delhi, India",
+    started: 2023,
+    graduates: YearMonth::new(2027, 5),
+    cgpa: "8.7/10.0",
+    coursework: &[
+        "Computer Networks",
+        "Data Structures & Algorithms",
+        "Probability & Statistics",
+        "Database Management Systems",
+        "Cybersecurity Fundamentals",
+    ],
+};
+
+pub const PROJECTS: &[Project] = &[
+    Project {
+        id: "ids",
+        title: "Network Intrusion Detection System",
+        tagline: "Network Intrusion Detection",
+        dates: DateRange::new(YearMonth::new(2024, 3), YearMonth::new(2024, 4)),
+        summary: "Built a basic intrusion detection system to identify network threats using machine learning algorithms.",
+        highlights_label: "Key Features",
+        highlights: &[
+            "Worked with NSL-KDD dataset",
+            "Implemented Random Forest & SVM classifiers",
+            "Used Scikit-learn for ML implementation",
+            "Feature engineering & model evaluation",
+            "Identified DoS and probing attacks",
+        ],
+        tech: &["Python", "Scikit-learn", "ML"],
+        tags: &["python", "ml", "security", "networking"],
+        links: &[],
+    },
+    Project {
+        id: "ransomware",
+        title: "Ransomware Simulation (Educational)",
+        tagline: "Ransomware Simulation",
+        dates: DateRange::new(YearMonth::new(2024, 5), YearMonth::new(2024, 6)),
+        summary: "Created a safe, non-malicious simulation to understand how ransomware works technically.",
+        highlights_label: "Learning Outcomes",
+        highlights: &[
+            "File encryption concepts",
+            "Prevention methods (backups, endpoint security)",
+            "Developed in isolated environments",
+            "Incident response procedures",
+            "Cybersecurity measures importance",
+        ],
+        tech: &["Python", "Encryption", "Security Research"],
+        tags: &["python", "security", "cryptography"],
+        links: &[],
+    },
+    Project {
+        id: "traffic",
+        title: "Network Traffic Analysis Tool",
+        tagline: "Network Traffic Analyzer",
+        dates: DateRange::new(YearMonth::new(2024, 2), YearMonth::new(2024, 3)),
+        summary: "Developed a simple packet analyzer using Python and Scapy to capture network traffic.",
+        highlights_label: "Key Features",
+        highlights: &[
+            "Packet capture using Scapy",
+            "Protocol identification",
+            "Network communication pattern analysis",
+            "Comparison with Wireshark",
+            "Deep understanding of packet structures",
+        ],
+        tech: &["Python", "Scapy", "Wireshark"],
+        tags: &["python", "networking", "security"],
+        links: &[],
+    },
+    Project {
+        id: "password",
+        title: "Password Security Analysis",
+        tagline: "Password Security Analysis",
+        dates: DateRange::new(YearMonth::new(2024, 1), YearMonth::new(2024, 2)),
+        summary: "Experimented with John the Ripper to understand password cracking techniques.",
+        highlights_label: "Learning Outcomes",
+        highlights: &[
+            "Password cracking techniques",
+            "Strong password practices",
+            "Security measures (salting & hashing)",
+            "Authentication system vulnerabilities",
+            "Common password weaknesses",
+        ],
+        tech: &["John the Ripper", "Security", "Analysis"],
+        tags: &["security", "cryptography", "passwords"],
+        links: &[],
+    },
+];
+
+pub const CERTIFICATIONS: &[Certification] = &[
+    Certification { name: "Introduction to Cybersecurity", issuer: "Cisco Networking Academy", year: Some(2023) },
+    Certification { name: "Introduction to Python Programming", issuer: "Cisco Networking Academy", year: Some(2023) },
+    Certification { name: "Artificial Intelligence Fundamentals", issuer: "IBM", year: Some(2023) },
+    Certification { name: "Machine Learning", issuer: "Colombia Plus University", year: None },
+    Certification { name: "Networking Basics", issuer: "Cisco Networking Academy", year: None },
+];
+
+pub const SKILLS: &[Skill] = &[
+    Skill { name: "Network Security Fundamentals", category: SkillCategory::Security, level: percent(75), years: None },
+    Skill { name: "Ethical Hacking Concepts", category: SkillCategory::Security, level: percent(70), years: None },
+    Skill { name: "OWASP Top 10", category: SkillCategory::Security, level: percent(65), years: None },
+    Skill { name: "Incident Response Awareness", category: SkillCategory::Security, level: percent(60), years: None },
+    Skill { name: "Wireshark", category: SkillCategory::Tools, level: percent(80), years: None },
+    Skill { name: "Nmap", category: SkillCategory::Tools, level: percent(75), years: None },
+    Skill { name: "Kali Linux", category: SkillCategory::Tools, level: percent(80), years: None },
+    Skill { name: "John the Ripper", category: SkillCategory::Tools, level: percent(70), years: None },
+    Skill { name: "Scapy", category: SkillCategory::Tools, level: percent(75), years: None },
+    Skill { name: "Burp Suite (basic)", category: SkillCategory::Tools, level: percent(60), years: None },
+    Skill { name: "Python", category: SkillCategory::Programming, level: percent(85), years: None },
+    Skill { name: "Bash Scripting", category: SkillCategory::Programming, level: percent(75), years: None },
+    Skill { name: "SQL", category: SkillCategory::Programming, level: percent(70), years: None },
+    Skill { name: "Linux/Ubuntu", category: SkillCategory::Programming, level: percent(85), years: None },
+    Skill { name: "TCP/IP", category: SkillCategory::Networking, level: percent(75), years: None },
+    Skill { name: "HTTP/HTTPS", category: SkillCategory::Networking, level: percent(70), years: None },
+    Skill { name: "DNS", category: SkillCategory::Networking, level: percent(70), years: None },
+    Skill { name: "Firewalls", category: SkillCategory::Networking, level: percent(65), years: None },
+    Skill { name: "Scikit-learn", category: SkillCategory::MachineLearning, level: percent(75), years: None },
+    Skill { name: "Pandas", category: SkillCategory::MachineLearning, level: percent(80), years: None },
+    Skill { name: "NumPy", category: SkillCategory::MachineLearning, level: percent(80), years: None },
+    Skill { name: "Random Forest", category: SkillCategory::MachineLearning, level: None, years: None },
+    Skill { name: "SVM", category: SkillCategory::MachineLearning, level: None, years: None },
+];
+
+pub const ACTIVITIES: &[&str] = &[
+    "Beginner-level CTF challenges",
+    "TryHackMe practical labs",
+    "Following cybersecurity news & research",
+    "Personal security projects",
+];
@@ -0,0 +1,312 @@
+//! `nano` (also `vi`): a small nano-style editor drawn in the output pane.
+//! Files are read and written through the virtual filesystem, so the
+//! visitor's own files persist and the portfolio's stay read-only.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use web_sys::{Element, KeyboardEvent};
+
+use crate::browser::escape_html;
+use crate::layout;
+use crate::terminal::{KeyHook, Terminal};
+use crate::vfs::{self, FsError};
+
+/// Text rows shown at once.
+const ROWS: usize = 14;
+const TAB: &str = "    ";
+
+struct Editor {
+    /// Absolute path of the file being edited.
+    path: String,
+    lines: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+    /// First line on screen.
+    top: usize,
+    modified: bool,
+    /// One-off message on the status line.
+    status: String,
+    /// Waiting for the answer to "Save modified buffer?".
+    confirm_exit: bool,
+    view: Option<Element>,
+}
+
+/// What a key press asks of the editor beyond changing the buffer.
+enum Action {
+    Stay,
+    Close,
+}
+
+pub fn nano_command(term: &Terminal, command: &str, args: &[&str]) -> Result<String, String> {
+    let [file] = args else {
+        return Err(format!("<span style='color: #888'>Usage: {} FILE   (your files are saved in this browser)</span>", command));
+    };
+    let path = vfs::resolve(&term.var("PWD").unwrap_or_default(), file);
+    let (text, status) = match vfs::read(&path) {
+        Ok(text) => {
+            let lines = text.lines().count();
+            (text, format!("[ Read {} line{} ]", lines, if lines == 1 { "" } else { "s" }))
+        }
+        Err(FsError::NotFound) => (String::new(), "[ New File ]".to_string()),
+        Err(err) => return Err(vfs::error(command, &path, err)),
+    };
+
+    let editor = Rc::new(RefCell::new(Editor {
+        path,
+        lines: text.split('\n').map(|line| line.chars().collect()).collect(),
+        row: 0,
+        col: 0,
+        top: 0,
+        modified: false,
+        status,
+        confirm_exit: false,
+        view: None,
+    }));
+    let view = term.print_live(&editor.borrow().render());
+    editor.borrow_mut().view = view;
+
+    let me = Rc::downgrade(&term.rc());
+    let hook: KeyHook = Rc::new(move |event: &KeyboardEvent| {
+        let Some(term) = me.upgrade() else { return false };
+        let mut ed = editor.borrow_mut();
+        let action = if ed.confirm_exit { ed.on_confirm(event) } else { ed.on_key(event) };
+        let Some(action) = action else { return false };
+        event.prevent_default();
+        match action {
+            Action::Stay => ed.redraw(),
+            Action::Close => ed.close(&term),
+        }
+        true
+    });
+    term.set_input("");
+    term.set_prompt("");
+    term.set_mode(hook);
+    term.scroll_to_bottom();
+    Ok(String::new())
+}
+
+impl Editor {
+    /// Handles a key while editing; `None` lets the browser have it.
+    fn on_key(&mut self, event: &KeyboardEvent) -> Option<Action> {
+        self.status.clear();
+        let key = event.key();
+        if event.ctrl_key() {
+            match key.as_str() {
+                "s" | "o" => self.save(),
+                "x" | "c" if self.modified => {
+                    self.confirm_exit = true;
+                    self.status = "Save modified buffer? (Y)es, (N)o, Esc to keep editing".to_string();
+                }
+                "x" | "c" => return Some(Action::Close),
+                "k" => self.cut_line(),
+                _ => return None,
+            }
+            return Some(Action::Stay);
+        }
+        if event.meta_key() || event.alt_key() {
+            return None;
+        }
+        match key.as_str() {
+            "ArrowLeft" => self.left(),
+            "ArrowRight" => self.right(),
+            "ArrowUp" => self.move_rows(-1),
+            "ArrowDown" => self.move_rows(1),
+            "PageUp" => self.move_rows(-(ROWS as isize)),
+            "PageDown" => self.move_rows(ROWS as isize),
+            "Home" => self.col = 0,
+            "End" => self.col = self.lines[self.row].len(),
+            "Enter" => self.newline(),
+            "Backspace" => self.backspace(),
+            "Delete" => self.delete(),
+            "Tab" => TAB.chars().for_each(|c| self.insert(c)),
+            "Escape" => {}
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => self.insert(c),
+                    // Named keys we don't use (F1, Shift, ...).
+                    _ => return Some(Action::Stay),
+                }
+            }
+        }
+        Some(Action::Stay)
+    }
+
+    fn on_confirm(&mut self, event: &KeyboardEvent) -> Option<Action> {
+        match event.key().as_str() {
+            "y" | "Y" => {
+                self.save();
+                if !self.modified {
+                    return Some(Action::Close);
+                }
+            }
+            "n" | "N" => return Some(Action::Close),
+            "Escape" => self.status.clear(),
+            "c" if event.ctrl_key() => self.status.clear(),
+            _ if event.ctrl_key() || event.meta_key() => return None,
+            _ => return Some(Action::Stay),
+        }
+        self.confirm_exit = false;
+        Some(Action::Stay)
+    }
+
+    fn save(&mut self) {
+        let text = self.text();
+        self.status = match vfs::write(&self.path, &text) {
+            Ok(()) => {
+                self.modified = false;
+                let lines = self.lines.len();
+                format!("[ Wrote {} line{} ]", lines, if lines == 1 { "" } else { "s" })
+            }
+            Err(err) => format!("[ Error writing {}: {} ]", vfs::display(&self.path), match err {
+                FsError::PermissionDenied => "Permission denied",
+                FsError::NotFound => "No such directory",
+                _ => "could not save",
+            }),
+        };
+    }
+
+    fn text(&self) -> String {
+        self.lines.iter().map(|line| line.iter().collect::<String>()).collect::<Vec<_>>().join("\n")
+    }
+
+    fn insert(&mut self, c: char) {
+        self.lines[self.row].insert(self.col, c);
+        self.col += 1;
+        self.modified = true;
+    }
+
+    fn newline(&mut self) {
+        let rest = self.lines[self.row].split_off(self.col);
+        self.lines.insert(self.row + 1, rest);
+        self.row += 1;
+        self.col = 0;
+        self.modified = true;
+    }
+
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            self.lines[self.row].remove(self.col);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+            self.lines[self.row].extend(line);
+        } else {
+            return;
+        }
+        self.modified = true;
+    }
+
+    fn delete(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.lines[self.row].remove(self.col);
+        } else if self.row + 1 < self.lines.len() {
+            let next = self.lines.remove(self.row + 1);
+            self.lines[self.row].extend(next);
+        } else {
+            return;
+        }
+        self.modified = true;
+    }
+
+    fn cut_line(&mut self) {
+        if self.lines.len() > 1 {
+            self.lines.remove(self.row);
+            self.row = self.row.min(self.lines.len() - 1);
+        } else {
+            self.lines[0].clear();
+        }
+        self.col = self.col.min(self.lines[self.row].len());
+        self.modified = true;
+    }
+
+    fn left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+        }
+    }
+
+    fn right(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    fn move_rows(&mut self, by: isize) {
+        self.row = self.row.saturating_add_signed(by).min(self.lines.len() - 1);
+        self.col = self.col.min(self.lines[self.row].len());
+    }
+
+    fn render(&self) -> String {
+        let width = layout::columns();
+        let title = format!(
+            "  nano-lite  {}{}",
+            vfs::display(&self.path), if self.modified { "  Modified" } else { "" }
+        );
+        let inverse = |text: &str| format!("<span style='background: #ccc; color: #000'>{}</span>", escape_html(text));
+
+        let mut out = vec![inverse(&layout::pad(&title, width))];
+        for row in self.top..self.top + ROWS {
+            let Some(line) = self.lines.get(row) else {
+                out.push(String::new());
+                continue;
+            };
+            if row != self.row {
+                out.push(escape_html(&line.iter().collect::<String>()));
+                continue;
+            }
+            let before: String = line[..self.col].iter().collect();
+            let at = line.get(self.col).map_or(" ".to_string(), |c| c.to_string());
+            let after: String = line.get(self.col + 1..).unwrap_or_default().iter().collect();
+            out.push(format!(
+                "{}<span style='background: #0f0; color: #000'>{}</span>{}",
+                escape_html(&before), escape_html(&at), escape_html(&after)
+            ));
+        }
+        out.push(format!("<span style='color: #ffc600'>{}</span>", escape_html(&layout::center(&self.status, width))));
+        let keys = if self.confirm_exit {
+            [("Y", "Yes"), ("N", "No"), ("Esc", "Cancel")].as_slice()
+        } else {
+            [("^S", "Save"), ("^X", "Exit"), ("^K", "Cut line")].as_slice()
+        };
+        let keys: Vec<String> = keys.iter().map(|(key, label)| format!("{} {}", inverse(key), label)).collect();
+        out.push(keys.join("   "));
+        out.join("\n")
+    }
+
+    fn redraw(&mut self) {
+        // Keep the cursor on screen.
+        if self.row < self.top {
+            self.top = self.row;
+        } else if self.row >= self.top + ROWS {
+            self.top = self.row + 1 - ROWS;
+        }
+        if let Some(view) = &self.view {
+            view.set_inner_html(&self.render());
+        }
+    }
+
+    /// Hands the keyboard back to the shell, leaving a one-line note where
+    /// the editor was.
+    fn close(&mut self, term: &Terminal) {
+        if let Some(view) = self.view.take() {
+            view.set_inner_html(&format!(
+                "<span style='color: #888'>nano: closed {}{}</span>",
+                escape_html(&vfs::display(&self.path)), if self.modified { " without saving" } else { "" }
+            ));
+        }
+        term.clear_mode();
+        term.set_input("");
+        term.reset_prompt();
+        term.scroll_to_bottom();
+    }
+}
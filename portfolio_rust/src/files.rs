@@ -0,0 +1,263 @@
+//! File builtins over the virtual filesystem: `ls`, `cat`, `cd`, `touch`,
+//! `mkdir`, `rm`, `mv`, `cp`, and `>` / `>>` output redirection.
+
+use crate::browser::escape_html;
+use crate::output;
+use crate::projects;
+use crate::script;
+use crate::terminal::Terminal;
+use crate::vfs::{self, FsError, HOME};
+
+fn cwd(term: &Terminal) -> String {
+    term.var("PWD").unwrap_or_else(|| HOME.to_string())
+}
+
+/// Splits leading `-x` flags from operands. `None` if a flag is not one of
+/// `allowed`.
+fn parse<'a>(args: &[&'a str], allowed: &str) -> Option<(String, Vec<&'a str>)> {
+    let mut flags = String::new();
+    let mut operands = Vec::new();
+    for arg in args {
+        match arg.strip_prefix('-') {
+            Some(f) if !f.is_empty() && operands.is_empty() => {
+                if !f.chars().all(|c| allowed.contains(c)) {
+                    return None;
+                }
+                flags.push_str(f);
+            }
+            _ => operands.push(*arg),
+        }
+    }
+    Some((flags, operands))
+}
+
+fn usage(text: &str) -> Result<String, String> {
+    Err(format!("<span style='color: #888'>Usage: {}</span>", text))
+}
+
+/// Nothing if there were no `errors`, else all of them as a failure.
+fn errors(errors: Vec<String>) -> Result<String, String> {
+    if errors.is_empty() { Ok(String::new()) } else { Err(errors.join("\n")) }
+}
+
+/// Runs `op` on each operand, collecting error lines.
+fn each(term: &Terminal, command: &str, operands: &[&str], op: impl Fn(&str) -> Result<(), FsError>) -> Result<String, String> {
+    let cwd = cwd(term);
+    errors(operands.iter()
+        .filter_map(|arg| {
+            let path = vfs::resolve(&cwd, arg);
+            op(&path).err().map(|err| vfs::error(command, &path, err))
+        })
+        .collect())
+}
+
+/// `ls [PATH...]`: the entries of each directory (read-only portfolio files
+/// dimmed), or the file itself. With no path, the current directory. Flags
+/// such as `-l` or `-a` are accepted and ignored.
+pub fn ls_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    let cwd = cwd(term);
+    let mut paths: Vec<&str> = args.iter().copied().filter(|arg| !arg.starts_with('-') || *arg == "-").collect();
+    if paths.is_empty() {
+        paths.push(".");
+    }
+    let failed = std::cell::Cell::new(false);
+    let listing = |arg: &str| {
+        let path = vfs::resolve(&cwd, arg);
+        match vfs::children(&path) {
+            Ok(entries) => entries.iter()
+                .map(|e| {
+                    let name = escape_html(&e.name);
+                    match (e.dir, e.read_only) {
+                        (true, _) => format!("<span style='color: #39f; font-weight: bold'>{}/</span>", name),
+                        (false, true) => format!("<span style='color: #888'>{}</span>", name),
+                        (false, false) => name,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("  "),
+            Err(FsError::NotADirectory) => escape_html(arg),
+            Err(err) => {
+                failed.set(true);
+                vfs::error("ls", &path, err)
+            }
+        }
+    };
+    let output = match paths.as_slice() {
+        [path] => listing(path),
+        paths => paths.iter()
+            .map(|path| format!("{}:\n{}", escape_html(path), listing(path)))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    };
+    if failed.get() { Err(output) } else { Ok(output) }
+}
+
+/// `cat FILE...`. A project file on its own gets the formatted project page.
+pub fn cat_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return usage("cat FILE...");
+    }
+    let cwd = cwd(term);
+    if let [arg] = args {
+        let path = vfs::resolve(&cwd, arg);
+        if let Some(detail) = path.strip_prefix(&format!("{}/projects/", HOME)).and_then(projects::project_detail) {
+            return Ok(detail);
+        }
+    }
+    let mut failed = false;
+    let output = args.iter()
+        .map(|arg| {
+            let path = vfs::resolve(&cwd, arg);
+            match vfs::read(&path) {
+                Ok(text) => escape_html(text.strip_suffix('\n').unwrap_or(&text)),
+                Err(err) => {
+                    failed = true;
+                    match err {
+                        FsError::NotFound => format!(
+                            "{}\n<span style='color: #888'>Try: ls ~ or cat projects/ids</span>",
+                            vfs::error("cat", &path, err)
+                        ),
+                        err => vfs::error("cat", &path, err),
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if failed { Err(output) } else { Ok(output) }
+}
+
+pub fn cd_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    let path = match args {
+        [] => HOME.to_string(),
+        [dir] => vfs::resolve(&cwd(term), dir),
+        _ => return usage("cd [DIR]"),
+    };
+    if vfs::is_dir(&path) {
+        term.set_cwd(&path);
+        Ok(String::new())
+    } else {
+        let err = if vfs::exists(&path) { FsError::NotADirectory } else { FsError::NotFound };
+        Err(vfs::error("cd", &path, err))
+    }
+}
+
+pub fn touch_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match parse(args, "") {
+        Some((_, files)) if !files.is_empty() => each(term, "touch", &files, vfs::touch),
+        _ => usage("touch FILE..."),
+    }
+}
+
+pub fn mkdir_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match parse(args, "p") {
+        Some((flags, dirs)) if !dirs.is_empty() => {
+            let parents = flags.contains('p');
+            each(term, "mkdir", &dirs, |path| vfs::mkdir(path, parents))
+        }
+        _ => usage("mkdir [-p] DIR..."),
+    }
+}
+
+pub fn rm_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match parse(args, "rRf") {
+        Some((flags, paths)) if !paths.is_empty() => {
+            let recursive = flags.contains(['r', 'R']);
+            let force = flags.contains('f');
+            each(term, "rm", &paths, |path| match vfs::remove(path, recursive) {
+                Err(FsError::NotFound) if force => Ok(()),
+                result => result,
+            })
+        }
+        _ => usage("rm [-rf] PATH..."),
+    }
+}
+
+/// `mv` and `cp`: the last operand is the destination; with several
+/// sources it must be a directory.
+fn transfer(term: &Terminal, command: &str, args: &[&str], flags_allowed: &str) -> Result<String, String> {
+    let Some((flags, operands)) = parse(args, flags_allowed) else {
+        return usage(&format!("{} [-{}] SOURCE... DEST", command, flags_allowed));
+    };
+    let [sources @ .., dest] = operands.as_slice() else {
+        return usage(&format!("{} SOURCE... DEST", command));
+    };
+    if sources.is_empty() {
+        return usage(&format!("{} SOURCE... DEST", command));
+    }
+    let cwd = cwd(term);
+    let dest = vfs::resolve(&cwd, dest);
+    if sources.len() > 1 && !vfs::is_dir(&dest) {
+        return Err(vfs::error(command, &dest, FsError::NotADirectory));
+    }
+    let recursive = flags.contains(['r', 'R']);
+    errors(sources.iter()
+        .filter_map(|source| {
+            let source = vfs::resolve(&cwd, source);
+            let result = if command == "mv" { vfs::rename(&source, &dest) } else { vfs::copy(&source, &dest, recursive) };
+            result.err().map(|err| match err {
+                // Say which side was refused.
+                FsError::PermissionDenied if command == "mv" && vfs::is_read_only(&source) => vfs::error(command, &source, err),
+                FsError::NotFound if !vfs::exists(&source) => vfs::error(command, &source, err),
+                FsError::IsADirectory if vfs::is_dir(&source) && command == "cp" => format!(
+                    "<span style='color: #f44'>cp: -r not specified; omitting directory '{}'</span>",
+                    escape_html(&vfs::display(&source))
+                ),
+                err => vfs::error(command, &vfs::destination(&source, &dest), err),
+            })
+        })
+        .collect())
+}
+
+pub fn mv_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.len() < 2 {
+        return usage("mv SOURCE... DEST");
+    }
+    transfer(term, "mv", args, "f")
+}
+
+pub fn cp_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.len() < 2 {
+        return usage("cp [-r] SOURCE... DEST");
+    }
+    transfer(term, "cp", args, "rRf")
+}
+
+pub struct Redirect<'a> {
+    pub command: &'a str,
+    pub target: &'a str,
+    pub append: bool,
+}
+
+/// Splits `command > file` or `command >> file` at the first unquoted `>`.
+/// `None` if there is no redirect; an error if there is no command or the
+/// target is not a single file name, so nothing runs. The target is left
+/// as written, for the caller to expand.
+pub fn split_redirect(line: &str) -> Option<Result<Redirect<'_>, String>> {
+    let at = script::find_unquoted(line, |c| c == '>')?;
+    let append = line[at + 1..].starts_with('>');
+    let command = line[..at].trim();
+    let target = line[at + 1 + usize::from(append)..].trim();
+    if command.is_empty() {
+        return Some(Err("<span style='color: #f44'>syntax error: expected a command before '&gt;'</span>".to_string()));
+    }
+    if target.is_empty() || script::find_unquoted(target, |c| c == '>' || c.is_whitespace()).is_some() {
+        return Some(Err("<span style='color: #f44'>syntax error: expected one file name after '&gt;'</span>".to_string()));
+    }
+    Some(Ok(Redirect { command, target, append }))
+}
+
+/// Writes a command's output to the redirect target as plain text. A
+/// failed command writes nothing; its error is passed on to be printed.
+pub fn redirect(term: &Terminal, redirect: &Redirect, result: Result<String, String>) -> Result<String, String> {
+    let mut text = output::plain_text(&result?);
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+    let path = vfs::resolve(&cwd(term), redirect.target);
+    let result = if redirect.append { vfs::append(&path, &text) } else { vfs::write(&path, &text) };
+    match result {
+        Ok(()) => Ok(String::new()),
+        Err(err) => Err(vfs::error("redirect", &path, err)),
+    }
+}
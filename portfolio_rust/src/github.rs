@@ -0,0 +1,120 @@
+//! `gh`: an offline repository browser over the snapshot baked in by
+//! `build.rs` from `data/github.json`.
+
+use crate::browser::escape_html;
+use crate::layout::{self, Align, Span, Table};
+use crate::output;
+
+pub struct Repo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub language: &'static str,
+    pub stars: u64,
+    pub url: &'static str,
+    pub readme: &'static str,
+}
+
+include!(concat!(env!("OUT_DIR"), "/github_snapshot.rs"));
+
+/// README lines shown by `gh repo`.
+const README_EXCERPT: usize = 12;
+
+const USAGE: &str = "<span style='color: #888'>Usage: gh repos | gh repo [name]</span>";
+
+pub fn gh_command(args: &[&str]) -> Result<String, String> {
+    match args {
+        ["repos"] | ["repo", "list"] => Ok(list_repos()),
+        ["repo", name] | ["repo", "view", name] => show_repo(name),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn language_color(language: &str) -> &'static str {
+    match language {
+        "Rust" => "#dea584",
+        "Python" => "#3572a5",
+        "Shell" => "#89e051",
+        _ => "#888",
+    }
+}
+
+/// A colored dot and the language name.
+fn language(name: &str) -> Span {
+    Span::html(format!("<span style='color: {}'>●</span> {}", language_color(name), escape_html(name)), &format!("● {}", name))
+}
+
+fn list_repos() -> String {
+    if REPOS.is_empty() {
+        return format!(
+            "<span style='color: #888'>No repositories in the snapshot yet. Browse them on {}</span>",
+            output::link(&format!("https://github.com/{}", SNAPSHOT_USER), &format!("github.com/{}", SNAPSHOT_USER))
+        );
+    }
+    let mut table = Table::new().align(2, Align::Right);
+    for r in REPOS {
+        table.row(vec![
+            Span::new(r.name, "color: #0ff"),
+            language(r.language),
+            Span::plain(&format!("★ {}", r.stars)),
+            Span::new(r.description, "color: #888"),
+        ]);
+    }
+    format!(
+        "
+{}
+
+{}
+
+<span style='color: #888'>💡 Use 'gh repo [name]' for details · snapshot from {}</span>
+{}
+",
+        layout::header(&format!("GITHUB · {}", SNAPSHOT_USER)), table.render(), SNAPSHOT_DATE, layout::footer()
+    )
+}
+
+fn show_repo(name: &str) -> Result<String, String> {
+    let Some(repo) = REPOS.iter().find(|r| r.name.eq_ignore_ascii_case(name)) else {
+        let suggestions: Vec<&str> = REPOS.iter()
+            .filter(|r| r.name.contains(&name.to_lowercase()))
+            .map(|r| r.name)
+            .collect();
+        let hint = if suggestions.is_empty() {
+            "Try: gh repos".to_string()
+        } else {
+            format!("Did you mean: {}?", suggestions.join(", "))
+        };
+        return Err(format!(
+            "<span style='color: #f44'>gh: repository '{}' not found</span>\n<span style='color: #888'>{}</span>",
+            escape_html(name), hint
+        ));
+    };
+
+    let lines: Vec<&str> = repo.readme.lines().collect();
+    let mut readme = escape_html(&lines[..lines.len().min(README_EXCERPT)].join("\n"));
+    if lines.len() > README_EXCERPT {
+        readme.push_str("\n<span style='color: #888'>...</span>");
+    }
+
+    let facts = layout::key_values("color: #0ff", vec![
+        ("Language".to_string(), language(repo.language)),
+        ("Stars".to_string(), Span::plain(&format!("★ {}", repo.stars))),
+        ("URL".to_string(), Span::html(output::link(repo.url, repo.url), repo.url)),
+    ]);
+
+    Ok(format!(
+        "
+{}
+
+<span style='color: #888'>{}</span>
+
+{}
+
+<span style='color: #f90; font-weight: bold'>README.md</span>
+{}
+
+{}
+",
+        layout::header(&format!("{}/{}", SNAPSHOT_USER, repo.name)), escape_html(&layout::reflow(repo.description, layout::width())),
+        facts, readme, layout::footer()
+    ))
+}
@@ -0,0 +1,175 @@
+//! Message catalog for the shell's own text (welcome, help, whoami, errors),
+//! in Fluent format under `locales/`. The language comes from `lang set`,
+//! remembered in localStorage, or else from the browser's preferences.
+
+use std::cell::Cell;
+
+use fluent_bundle::{FluentArgs, FluentBundle, FluentResource};
+use unic_langid::LanguageIdentifier;
+use web_sys::window;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang {
+    En,
+    Hi,
+}
+
+impl Lang {
+    pub const ALL: [Lang; 2] = [Lang::En, Lang::Hi];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Hi => "hi",
+        }
+    }
+
+    /// The language's name in itself.
+    pub fn name(self) -> &'static str {
+        match self {
+            Lang::En => "English",
+            Lang::Hi => "हिन्दी",
+        }
+    }
+
+    /// Matches `hi`, `hi-IN`, `EN-us`...
+    pub fn from_tag(tag: &str) -> Option<Lang> {
+        let primary = tag.split(['-', '_']).next()?.to_lowercase();
+        Lang::ALL.into_iter().find(|l| l.code() == primary)
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/en.ftl"),
+            Lang::Hi => include_str!("../locales/hi.ftl"),
+        }
+    }
+}
+
+const STORAGE_KEY: &str = "portfolio-lang";
+
+struct Catalog {
+    en: FluentBundle<FluentResource>,
+    hi: FluentBundle<FluentResource>,
+}
+
+fn bundle(lang: Lang) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = lang.code().parse().expect("language codes are valid tags");
+    let resource = FluentResource::try_new(lang.source().replace("\r\n", "\n"))
+        .unwrap_or_else(|(resource, _)| resource);
+    let mut bundle = FluentBundle::new(vec![id]);
+    // Output is HTML in a terminal, not bidi text: skip the isolation marks.
+    bundle.set_use_isolating(false);
+    let _ = bundle.add_resource(resource);
+    bundle
+}
+
+thread_local! {
+    static CATALOG: Catalog = Catalog { en: bundle(Lang::En), hi: bundle(Lang::Hi) };
+    static CURRENT: Cell<Lang> = const { Cell::new(Lang::En) };
+}
+
+pub fn current() -> Lang {
+    CURRENT.with(Cell::get)
+}
+
+/// Switches language for the whole page and remembers the choice.
+pub fn set(lang: Lang) {
+    CURRENT.with(|c| c.set(lang));
+    if let Some(storage) = window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(STORAGE_KEY, lang.code());
+    }
+    mark_document(lang);
+}
+
+/// Picks the startup language: a saved `lang set`, else the first of the
+/// browser's preferred languages we have a catalog for.
+pub fn init() {
+    let saved = window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(STORAGE_KEY).ok().flatten())
+        .and_then(|tag| Lang::from_tag(&tag));
+    let detected = || {
+        let navigator = window()?.navigator();
+        navigator.languages().iter()
+            .filter_map(|tag| tag.as_string())
+            .chain(navigator.language())
+            .find_map(|tag| Lang::from_tag(&tag))
+    };
+    let lang = saved.or_else(detected).unwrap_or(Lang::En);
+    CURRENT.with(|c| c.set(lang));
+    mark_document(lang);
+}
+
+/// Keeps `<html lang>` in step so the browser shapes the script correctly.
+fn mark_document(lang: Lang) {
+    if let Some(root) = window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+        let _ = root.set_attribute("lang", lang.code());
+    }
+}
+
+/// The message `id` in the current language.
+pub fn t(id: &str) -> String {
+    format(id, None)
+}
+
+/// The message `id` with `{ $name }` placeables filled from `args`.
+pub fn t_args(id: &str, args: &[(&str, &str)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, *value);
+    }
+    format(id, Some(&fluent_args))
+}
+
+fn format(id: &str, args: Option<&FluentArgs>) -> String {
+    CATALOG.with(|catalog| {
+        let preferred = match current() {
+            Lang::En => &catalog.en,
+            Lang::Hi => &catalog.hi,
+        };
+        // Untranslated messages fall back to English, then to the id itself.
+        [preferred, &catalog.en]
+            .into_iter()
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(id)?.value()?;
+                let mut errors = Vec::new();
+                Some(bundle.format_pattern(pattern, args, &mut errors).into_owned())
+            })
+            .unwrap_or_else(|| id.to_string())
+    })
+}
+
+pub fn lang_command(args: &[&str]) -> Result<String, String> {
+    match args {
+        [] => Ok(format!(
+            "<span style='color: #0ff'>{}</span>\n<span style='color: #888'>{}</span>\n<span style='color: #888'>{}</span>",
+            t_args("lang-current", &[("name", current().name()), ("code", current().code())]),
+            t_args("lang-available", &[("list", &available())]),
+            t("lang-note")
+        )),
+        ["list"] => Ok(Lang::ALL.iter()
+            .map(|l| {
+                let marker = if *l == current() { "*" } else { " " };
+                format!("{} <span style='color: #0ff'>{}</span>  {}", marker, l.code(), l.name())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")),
+        ["set", code] => match Lang::from_tag(code) {
+            Some(lang) => {
+                set(lang);
+                Ok(format!("<span style='color: #0f0'>{}</span>", t_args("lang-changed", &[("name", lang.name())])))
+            }
+            None => Err(format!(
+                "<span style='color: #f44'>{}</span>\n<span style='color: #888'>{}</span>",
+                t_args("lang-unknown", &[("code", &crate::browser::escape_html(code))]),
+                t_args("lang-available", &[("list", &available())])
+            )),
+        },
+        _ => Err(format!("<span style='color: #888'>{}</span>", t("lang-usage"))),
+    }
+}
+
+fn available() -> String {
+    Lang::ALL.iter().map(|l| format!("{} ({})", l.code(), l.name())).collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,276 @@
+//! Long-running (async) commands: they show a spinner while they work and
+//! can be cancelled with Ctrl+C.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use futures::future::{AbortHandle, FutureExt, LocalBoxFuture};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, AbortController, RequestInit, Response};
+
+use crate::browser;
+use crate::terminal::Terminal;
+
+pub type Task = LocalBoxFuture<'static, String>;
+
+pub const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Status text shown next to the spinner; the task updates it as it goes.
+#[derive(Clone)]
+pub struct Progress(Rc<RefCell<String>>);
+
+impl Progress {
+    pub fn new(label: &str) -> Progress {
+        Progress(Rc::new(RefCell::new(label.to_string())))
+    }
+
+    pub fn set(&self, text: impl Into<String>) {
+        *self.0.borrow_mut() = text.into();
+    }
+
+    pub fn get(&self) -> String {
+        self.0.borrow().clone()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum JobState {
+    Running,
+    Done,
+    Killed,
+}
+
+impl JobState {
+    fn label(self) -> &'static str {
+        match self {
+            JobState::Running => "Running",
+            JobState::Done => "Done",
+            JobState::Killed => "Terminated",
+        }
+    }
+}
+
+pub struct Job {
+    pub id: usize,
+    pub command: String,
+    pub state: JobState,
+    pub abort: AbortHandle,
+    pub progress: Progress,
+    /// Output held back until the job is brought to the foreground.
+    pub output: Option<String>,
+}
+
+/// The shell's job table: numbered jobs, most recent last.
+#[derive(Default)]
+pub struct JobTable {
+    jobs: Vec<Job>,
+}
+
+impl JobTable {
+    pub fn add(&mut self, command: &str, abort: AbortHandle, progress: Progress) -> usize {
+        let id = self.jobs.last().map_or(1, |j| j.id + 1);
+        self.jobs.push(Job { id, command: command.to_string(), state: JobState::Running, abort, progress, output: None });
+        id
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Job> {
+        self.jobs.iter().find(|j| j.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut Job> {
+        self.jobs.iter_mut().find(|j| j.id == id)
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Job> {
+        let index = self.jobs.iter().position(|j| j.id == id)?;
+        Some(self.jobs.remove(index))
+    }
+
+    pub fn latest(&self) -> Option<usize> {
+        self.jobs.last().map(|j| j.id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Job> {
+        self.jobs.iter()
+    }
+}
+
+/// Splits a trailing `&` off a command line.
+pub fn strip_background(command: &str) -> (&str, bool) {
+    match command.strip_suffix('&') {
+        Some(rest) if !rest.ends_with('&') => (rest.trim_end(), true),
+        _ => (command, false),
+    }
+}
+
+/// Parses `%1`, `1`, `%%` or `%+`; no argument means the most recent job.
+fn parse_job_spec(term: &Terminal, args: &[&str]) -> Result<usize, String> {
+    let latest = || term.with_jobs(|jobs| jobs.latest()).ok_or_else(|| "no current job".to_string());
+    match args.first() {
+        None | Some(&"%%") | Some(&"%+") => latest(),
+        Some(spec) => spec.trim_start_matches('%').parse::<usize>()
+            .ok()
+            .filter(|id| term.with_jobs(|jobs| jobs.get(*id).is_some()))
+            .ok_or_else(|| format!("{}: no such job", browser::escape_html(spec))),
+    }
+}
+
+pub fn jobs_command(term: &Terminal) -> String {
+    let (lines, reported) = term.with_jobs(|jobs| {
+        let latest = jobs.latest();
+        let lines: Vec<String> = jobs.iter()
+            .map(|job| {
+                let marker = if Some(job.id) == latest { "+" } else { " " };
+                let color = match job.state {
+                    JobState::Running => "#0f0",
+                    JobState::Done => "#0ff",
+                    JobState::Killed => "#f44",
+                };
+                let waiting = if job.output.as_deref().is_some_and(|o| !o.is_empty()) {
+                    format!("  <span style='color: #888'>(output waiting: fg %{})</span>", job.id)
+                } else {
+                    String::new()
+                };
+                format!(
+                    "[{}]{}  <span style='color: {}'>{:<10}</span> {} &amp;{}",
+                    job.id, marker, color, job.state.label(), browser::escape_html(&job.command), waiting
+                )
+            })
+            .collect();
+        let reported: Vec<usize> = jobs.iter()
+            .filter(|j| j.state != JobState::Running && j.output.as_deref().is_none_or(str::is_empty))
+            .map(|j| j.id)
+            .collect();
+        (lines, reported)
+    });
+    // Like bash, finished jobs are forgotten once they have been reported.
+    term.with_jobs_mut(|jobs| {
+        for id in reported {
+            jobs.remove(id);
+        }
+    });
+    if lines.is_empty() {
+        "<span style='color: #888'>No jobs. Run a command with a trailing '&' to start one.</span>".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+pub fn fg_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match parse_job_spec(term, args) {
+        Ok(id) => {
+            if let Some(command) = term.with_jobs(|jobs| jobs.get(id).map(|j| j.command.clone())) {
+                term.print_output(&browser::escape_html(&command));
+            }
+            term.attach_job(id);
+            Ok(String::new())
+        }
+        Err(err) => Err(format!("<span style='color: #f44'>fg: {}</span>", err)),
+    }
+}
+
+pub fn kill_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return Err("<span style='color: #888'>Usage: kill %JOB</span>".to_string());
+    }
+    let mut out = Vec::new();
+    for arg in args {
+        match parse_job_spec(term, &[arg]) {
+            Ok(id) => {
+                let state = term.with_jobs(|jobs| jobs.get(id).map(|j| j.state));
+                if state == Some(JobState::Running) {
+                    term.with_jobs(|jobs| {
+                        if let Some(job) = jobs.get(id) {
+                            job.abort.abort();
+                        }
+                    });
+                } else {
+                    term.with_jobs_mut(|jobs| jobs.remove(id));
+                    out.push(format!("<span style='color: #f44'>kill: %{}: job has already completed</span>", id));
+                }
+            }
+            Err(err) => out.push(format!("<span style='color: #f44'>kill: {}</span>", err)),
+        }
+    }
+    if out.is_empty() { Ok(String::new()) } else { Err(out.join("\n")) }
+}
+
+/// Commands that finish after the line that started them, as jobs. Scripts
+/// run synchronously and cannot wait for them.
+pub const ASYNC_COMMANDS: [&str; 2] = ["sleep", "curl"];
+
+/// Returns a task for commands that run asynchronously, `None` for the rest.
+pub fn async_command(parts: &[&str], progress: &Progress) -> Option<Task> {
+    let command = parts.first()?.to_lowercase();
+    let args: Vec<String> = parts[1..].iter().map(|s| s.to_string()).collect();
+    let progress = progress.clone();
+
+    match command.as_str() {
+        "sleep" => Some(sleep_command(args, progress).boxed_local()),
+        "curl" => Some(curl_command(args, progress).boxed_local()),
+        _ => None,
+    }
+}
+
+async fn sleep_command(args: Vec<String>, progress: Progress) -> String {
+    let Some(seconds) = args.first().and_then(|s| s.parse::<f64>().ok()).filter(|s| *s >= 0.0) else {
+        return "<span style='color: #888'>Usage: sleep SECONDS</span>".to_string();
+    };
+    let mut left = seconds;
+    while left > 0.0 {
+        progress.set(format!("sleep {} ({:.0}s left)", seconds, left.ceil()));
+        let step = left.min(1.0);
+        browser::sleep((step * 1000.0) as i32).await;
+        left -= step;
+    }
+    String::new()
+}
+
+async fn curl_command(args: Vec<String>, progress: Progress) -> String {
+    let Some(url) = args.first() else {
+        return "<span style='color: #888'>Usage: curl URL</span>".to_string();
+    };
+    progress.set(format!("Fetching {}", url));
+    match fetch_text(url).await {
+        Ok((status, body)) => {
+            let lines: Vec<&str> = body.lines().collect();
+            let shown = lines.iter().take(40).copied().collect::<Vec<_>>().join("\n");
+            let more = if lines.len() > 40 {
+                format!("\n<span style='color: #888'>... {} more lines</span>", lines.len() - 40)
+            } else {
+                String::new()
+            };
+            format!("<span style='color: #888'>HTTP {}</span>\n{}{}", status, browser::escape_html(&shown), more)
+        }
+        Err(err) => format!(
+            "<span style='color: #f44'>curl: {}</span>",
+            browser::escape_html(&err.as_string().unwrap_or_else(|| "request failed (network or CORS)".to_string()))
+        ),
+    }
+}
+
+/// Aborts the underlying request when the task is dropped (e.g. on Ctrl+C).
+struct AbortOnDrop(AbortController);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+async fn fetch_text(url: &str) -> Result<(u16, String), JsValue> {
+    fetch(url, RequestInit::new()).await
+}
+
+/// Fetches `url` and returns the status and body text; dropping the future
+/// cancels the request.
+pub async fn fetch(url: &str, init: RequestInit) -> Result<(u16, String), JsValue> {
+    let window = window().ok_or_else(|| JsValue::from_str("no window"))?;
+    let controller = AbortOnDrop(AbortController::new()?);
+    init.set_signal(Some(&controller.0.signal()));
+
+    let response: Response = JsFuture::from(window.fetch_with_str_and_init(url, &init)).await?.dyn_into()?;
+    let body = JsFuture::from(response.text()?).await?;
+    Ok((response.status(), body.as_string().unwrap_or_default()))
+}
@@ -0,0 +1,379 @@
+//! Text measured in terminal columns rather than bytes or chars, so padding
+//! and centering hold up for emoji and non-Latin scripts (Devanagari marks
+//! such as the virama take no column of their own while spacing vowel signs
+//! such as `ा` and `ि` take one, CJK takes two), and the panels, tables and
+//! key/value lists built on that measurement.
+//!
+//! Renderers lay out for [`columns`], the width of the terminal the current
+//! command runs in, and fall back to compact layouts when the wide ones
+//! would not fit.
+
+use std::cell::Cell;
+use std::fmt;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::browser::escape_html;
+
+/// Columns of the `═══` rules framing every section on a wide terminal.
+pub const WIDTH: usize = 51;
+
+/// Narrowest terminal renderers lay out for; anything smaller still gets
+/// this many columns and wraps.
+const MIN_COLUMNS: usize = 20;
+
+const RULE_STYLE: &str = "color: #ffc600; font-weight: bold";
+
+thread_local! {
+    static COLUMNS: Cell<usize> = const { Cell::new(80) };
+}
+
+/// Sets the terminal width the next renderers lay out for.
+pub fn set_columns(columns: usize) {
+    COLUMNS.with(|c| c.set(columns.max(MIN_COLUMNS)));
+}
+
+/// Width of the terminal output is being laid out for.
+pub fn columns() -> usize {
+    COLUMNS.with(Cell::get)
+}
+
+/// Width of section rules and panels: [`WIDTH`], or less on a narrow terminal.
+pub fn width() -> usize {
+    columns().min(WIDTH)
+}
+
+/// Columns one grapheme cluster occupies. Emoji sequences (ZWJ families,
+/// skin tones, variation selectors) are drawn as a single wide glyph, not
+/// as the sum of their parts.
+fn grapheme_width(grapheme: &str) -> usize {
+    let wide = grapheme.chars().next().and_then(UnicodeWidthChar::width) == Some(2);
+    if wide || grapheme.contains('\u{fe0f}') {
+        2
+    } else {
+        grapheme.width()
+    }
+}
+
+/// Columns `text` occupies on screen.
+pub fn display_width(text: &str) -> usize {
+    text.graphemes(true).map(grapheme_width).sum()
+}
+
+/// Pads `text` with spaces on the right to `width` columns.
+pub fn pad(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(display_width(text))))
+}
+
+/// Left padding that centers `text` in `width` columns.
+pub fn center(text: &str, width: usize) -> String {
+    format!("{}{}", " ".repeat(width.saturating_sub(display_width(text)) / 2), text)
+}
+
+/// Cuts `text` to at most `width` columns, ending in `…` if anything was
+/// dropped. Never splits a grapheme cluster.
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let w = grapheme_width(grapheme);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push_str(grapheme);
+        used += w;
+    }
+    out.push('…');
+    out
+}
+
+/// Breaks `text` into lines of at most `width` columns at spaces, splitting
+/// words only when a single word is wider than a line. Existing line breaks
+/// are kept.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            let needed = display_width(&line) + usize::from(!line.is_empty()) + display_width(word);
+            if needed <= width {
+                if !line.is_empty() {
+                    line.push(' ');
+                }
+                line.push_str(word);
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for grapheme in word.graphemes(true) {
+                if display_width(&line) + grapheme_width(grapheme) > width {
+                    lines.push(std::mem::take(&mut line));
+                }
+                line.push_str(grapheme);
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Wraps `text` to `width` columns, or the terminal if that is narrower.
+pub fn reflow(text: &str, width: usize) -> String {
+    wrap(text, width.min(columns())).join("\n")
+}
+
+/// A piece of output: markup plus the visible text it is measured by.
+#[derive(Clone)]
+pub struct Span {
+    html: String,
+    width: usize,
+}
+
+impl Span {
+    /// `text` in an inline `style` (empty for none).
+    pub fn new(text: &str, style: &str) -> Self {
+        let html = if style.is_empty() {
+            escape_html(text)
+        } else {
+            format!("<span style='{}'>{}</span>", style, escape_html(text))
+        };
+        Span { html, width: display_width(text) }
+    }
+
+    pub fn plain(text: &str) -> Self {
+        Span::new(text, "")
+    }
+
+    /// Prebuilt markup (a link, a bar) that shows as `text`.
+    pub fn html(html: impl fmt::Display, text: &str) -> Self {
+        Span { html: html.to_string(), width: display_width(text) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.html)
+    }
+}
+
+/// A line of `═` `width` columns long.
+pub fn rule(width: usize) -> String {
+    "═".repeat(width)
+}
+
+/// The title block that opens a section: rule, centered title, rule.
+pub fn header(title: &str) -> String {
+    header_with_note(title, None)
+}
+
+/// Like [`header`], with a dim line under the title (active filters, say).
+pub fn header_with_note(title: &str, note: Option<&str>) -> String {
+    let mut lines = vec![Span::new(&center(title, width()), "color: #fff; font-weight: bold")];
+    lines.extend(note.map(|n| Span::new(&center(n, width()), "color: #888")));
+    banner(&lines)
+}
+
+/// `lines` as they are, between two rules.
+pub fn banner(lines: &[Span]) -> String {
+    let rule = Span::new(&rule(width()), RULE_STYLE);
+    let lines: Vec<String> = lines.iter().map(Span::to_string).collect();
+    format!("{rule}\n{}\n{rule}", lines.join("\n"), rule = rule)
+}
+
+/// The rule that closes a section.
+pub fn footer() -> String {
+    Span::new(&rule(width()), "color: #ffc600").to_string()
+}
+
+/// `lines` inside a double-line box drawn in `border` style, as wide as
+/// the section rules or the widest line, whichever is more.
+pub fn panel(lines: &[Vec<Span>], border: &str) -> String {
+    const MARGIN: usize = 2;
+    let inner = lines.iter()
+        .map(|line| line.iter().map(Span::width).sum::<usize>() + 2 * MARGIN)
+        .max()
+        .unwrap_or(0)
+        .max(width() - 2);
+    let edge = |left: &str, right: &str| Span::new(&format!("{}{}{}", left, rule(inner), right), border).to_string();
+    let side = Span::new("║", border);
+
+    let mut out = vec![edge("╔", "╗")];
+    for line in lines {
+        let used: usize = line.iter().map(Span::width).sum();
+        let body: String = line.iter().map(Span::to_string).collect();
+        out.push(format!(
+            "{side}{}{}{}{side}",
+            " ".repeat(MARGIN), body, " ".repeat(inner - MARGIN - used), side = side
+        ));
+    }
+    out.push(edge("╚", "╝"));
+    out.join("\n")
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Rows of cells laid out in columns sized to their widest cell. The last
+/// column is never padded, so it can hold free text. A table too wide for
+/// the terminal falls back to one block per row: the first cell, then the
+/// rest indented beneath it.
+pub struct Table {
+    rows: Vec<Vec<Span>>,
+    align: Vec<Align>,
+    indent: usize,
+    gap: usize,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Table { rows: Vec::new(), align: Vec::new(), indent: 0, gap: 2 }
+    }
+
+    /// Spaces before every row.
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Spaces between columns.
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn align(mut self, column: usize, align: Align) -> Self {
+        if self.align.len() <= column {
+            self.align.resize(column + 1, Align::Left);
+        }
+        self.align[column] = align;
+        self
+    }
+
+    pub fn row(&mut self, cells: Vec<Span>) {
+        self.rows.push(cells);
+    }
+
+    pub fn render(&self) -> String {
+        let count = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..count)
+            .map(|c| self.rows.iter().filter_map(|r| r.get(c)).map(Span::width).max().unwrap_or(0))
+            .collect();
+        let row_width = |row: &Vec<Span>| {
+            let padded: usize = widths[..row.len().saturating_sub(1)].iter().map(|w| w + self.gap).sum();
+            self.indent + padded + row.last().map_or(0, Span::width)
+        };
+        if count > 1 && self.rows.iter().map(row_width).max().unwrap_or(0) > columns() {
+            return self.render_stacked();
+        }
+        self.rows.iter()
+            .map(|row| {
+                let mut line = " ".repeat(self.indent);
+                for (c, cell) in row.iter().enumerate() {
+                    let fill = " ".repeat(widths[c] - cell.width());
+                    let last = c + 1 == row.len();
+                    match self.align.get(c).copied().unwrap_or(Align::Left) {
+                        Align::Right => line.push_str(&format!("{}{}", fill, cell)),
+                        Align::Left if last => line.push_str(&cell.to_string()),
+                        Align::Left => line.push_str(&format!("{}{}", cell, fill)),
+                    }
+                    if !last {
+                        line.push_str(&" ".repeat(self.gap));
+                    }
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_stacked(&self) -> String {
+        let indent = " ".repeat(self.indent);
+        self.rows.iter()
+            .map(|row| {
+                let rest: Vec<String> = row.iter().skip(1).map(Span::to_string).collect();
+                let first = row.first().map(Span::to_string).unwrap_or_default();
+                if rest.is_empty() {
+                    format!("{}{}", indent, first)
+                } else {
+                    format!("{}{}\n{}  {}", indent, first, indent, rest.join(" "))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Table::new()
+    }
+}
+
+/// `Label: value` lines with the values lined up after the widest label.
+pub fn key_values(key_style: &str, pairs: Vec<(String, Span)>) -> String {
+    let mut table = Table::new().gap(1);
+    for (key, value) in pairs {
+        table.row(vec![Span::new(&format!("{}:", key), key_style), value]);
+    }
+    table.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoji_sequences_are_one_wide_glyph() {
+        // Family: man, woman, girl joined with ZWJ.
+        assert_eq!(display_width("👨\u{200d}👩\u{200d}👧"), 2);
+        // Heart with VS16 asks for emoji presentation.
+        assert_eq!(display_width("❤\u{fe0f}"), 2);
+        assert_eq!(display_width("👍🏽 ok"), 5);
+    }
+
+    #[test]
+    fn devanagari_spacing_signs_take_a_column() {
+        // क + ा and क + ि: the vowel signs sit beside the consonant.
+        assert_eq!(display_width("का"), 2);
+        assert_eq!(display_width("कि"), 2);
+        // Virama and anusvara are marks on the consonant.
+        assert_eq!(display_width("क्"), 1);
+        assert_eq!(display_width("हिंदी"), 4);
+    }
+
+    #[test]
+    fn truncate_keeps_grapheme_clusters_whole() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(truncate(&format!("ab{}cd", family), 4), "ab…");
+        assert_eq!(truncate(&format!("ab{}cd", family), 5), format!("ab{}…", family));
+        assert_eq!(truncate("कि कि", 3), "कि…");
+        assert_eq!(truncate("short", 10), "short");
+    }
+
+    #[test]
+    fn table_stacks_when_rows_do_not_fit() {
+        let table = || {
+            let mut table = Table::new().indent(2).gap(1);
+            table.row(vec![Span::plain("name"), Span::plain("a fairly long description")]);
+            table
+        };
+        set_columns(80);
+        assert_eq!(table().render(), "  name a fairly long description");
+        set_columns(20);
+        assert_eq!(table().render(), "  name\n    a fairly long description");
+        set_columns(80);
+    }
+}
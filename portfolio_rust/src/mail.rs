@@ -0,0 +1,191 @@
+//! `mail` / `contact --send`: a small form that asks for the message one
+//! field at a time, previews it and sends it.
+//!
+//! Messages are POSTed as JSON to `$MAIL_ENDPOINT` (or the endpoint baked in
+//! at build time through `PORTFOLIO_MAIL_ENDPOINT`). Without an endpoint, or
+//! when sending fails, the visitor gets a prefilled `mailto:` link instead.
+
+use futures::FutureExt;
+use web_sys::RequestInit;
+
+use crate::browser::{self, escape_html};
+use crate::content::PROFILE;
+use crate::jobs;
+use crate::output;
+use crate::prompt;
+use crate::record::json_string;
+use crate::terminal::Terminal;
+
+const DEFAULT_SUBJECT: &str = "Hello from your portfolio";
+
+/// What to do with the finished message, in the order offered.
+const ACTIONS: [&str; 3] = ["Send it", "Use my mail client", "Discard"];
+
+#[derive(Default)]
+struct Message {
+    name: String,
+    email: String,
+    subject: String,
+    body: Vec<String>,
+}
+
+pub fn mail_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if let Some(arg) = args.first() {
+        return Err(format!(
+            "<span style='color: #f44'>mail: unexpected argument '{}'</span>\n<span style='color: #888'>Usage: mail</span>",
+            escape_html(arg)
+        ));
+    }
+
+    let term = term.rc();
+    wasm_bindgen_futures::spawn_local(async move { compose(&term).await });
+
+    Ok(format!(
+        "<span style='color: #ffc600; font-weight: bold'>✉️  Send a message to {}</span>\n\
+<span style='color: #888'>Answer each prompt and press Enter. Finish the message with an empty line. Esc cancels.</span>",
+        PROFILE.name
+    ))
+}
+
+async fn compose(term: &Terminal) {
+    let Some(message) = fill_in(term).await else {
+        return discarded(term);
+    };
+    term.print_output_colored(&preview(&message));
+    loop {
+        match prompt::select(term, "What now?", &ACTIONS).await {
+            Some(0) => return send(term, message),
+            Some(1) => return open_mail_client(term, &mailto_url(&message), "Opening your mail client..."),
+            // A typed-out message is worth a second thought; Esc still
+            // gets out without one.
+            Some(_) => {
+                if prompt::confirm(term, "Discard this message?", false).await != Some(false) {
+                    return discarded(term);
+                }
+            }
+            None => return discarded(term),
+        }
+    }
+}
+
+fn discarded(term: &Terminal) {
+    term.print_output_colored("<span style='color: #888'>Message discarded.</span>");
+}
+
+/// Asks for `label` until `check` accepts the (trimmed) answer.
+async fn ask_until(term: &Terminal, label: &str, check: impl Fn(&str) -> Result<(), &'static str>) -> Option<String> {
+    loop {
+        let answer = prompt::read_line(term, label).await?;
+        match check(answer.trim()) {
+            Ok(()) => return Some(answer.trim().to_string()),
+            Err(problem) => term.print_output_colored(&format!("<span style='color: #f44'>{}</span>", problem)),
+        }
+    }
+}
+
+async fn fill_in(term: &Terminal) -> Option<Message> {
+    let name = ask_until(term, "Name:", |v| if v.is_empty() { Err("Please enter your name.") } else { Ok(()) }).await?;
+    let email = ask_until(term, "Email:", |v| {
+        if valid_email(v) { Ok(()) } else { Err("That doesn't look like an email address (name@example.com).") }
+    }).await?;
+    let subject = prompt::read_line(term, "Subject:").await?;
+    let subject = if subject.trim().is_empty() { DEFAULT_SUBJECT.to_string() } else { subject.trim().to_string() };
+
+    let mut body = Vec::new();
+    loop {
+        let line = prompt::read_line(term, "Message>").await?;
+        match line.trim_end() {
+            "" if body.is_empty() => term.print_output_colored("<span style='color: #f44'>The message can't be empty.</span>"),
+            "" => break,
+            line => body.push(line.to_string()),
+        }
+    }
+    Some(Message { name, email, subject, body })
+}
+
+fn preview(message: &Message) -> String {
+    let body: Vec<String> = message.body.iter().map(|line| format!("  {}", escape_html(line))).collect();
+    format!(
+        "
+<span style='color: #ffc600'>───────────────────────── Preview ─────────────────────────</span>
+<span style='color: #f90'>From:</span>    {} &lt;{}&gt;
+<span style='color: #f90'>To:</span>      {}
+<span style='color: #f90'>Subject:</span> {}
+
+{}
+<span style='color: #ffc600'>───────────────────────────────────────────────────────────</span>",
+        escape_html(&message.name), escape_html(&message.email), PROFILE.email,
+        escape_html(&message.subject), body.join("\n")
+    )
+}
+
+/// A deliberately loose check: one `@`, something before it and a dotted
+/// domain after it.
+fn valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else { return false };
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.split('.').count() >= 2
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+fn endpoint(term: &Terminal) -> Option<String> {
+    term.var("MAIL_ENDPOINT")
+        .or_else(|| option_env!("PORTFOLIO_MAIL_ENDPOINT").map(str::to_string))
+        .filter(|url| !url.is_empty())
+}
+
+fn mailto_url(message: &Message) -> String {
+    let body = format!("{}\n\n-- \n{} <{}>", message.body.join("\n"), message.name, message.email);
+    format!(
+        "mailto:{}?subject={}&body={}",
+        PROFILE.email,
+        String::from(js_sys::encode_uri_component(&message.subject)),
+        String::from(js_sys::encode_uri_component(&body))
+    )
+}
+
+fn open_mail_client(term: &Terminal, url: &str, note: &str) {
+    let _ = browser::open_url(url);
+    term.print_output_colored(&format!(
+        "<span style='color: #888'>{}</span>\n<span style='color: #888'>If nothing happened, use this link:</span> {}",
+        note, output::link(url, "✉️  compose email")
+    ));
+}
+
+fn send(term: &Terminal, message: Message) {
+    let Some(url) = endpoint(term) else {
+        let mailto = mailto_url(&message);
+        open_mail_client(term, &mailto, "No mail endpoint is configured, so your mail client will send it.");
+        return;
+    };
+
+    let payload = format!(
+        "{{\"name\":{},\"email\":{},\"subject\":{},\"message\":{}}}",
+        json_string(&message.name), json_string(&message.email),
+        json_string(&message.subject), json_string(&message.body.join("\n"))
+    );
+    let task = async move {
+        let init = RequestInit::new();
+        init.set_method("POST");
+        init.set_body(&payload.into());
+        let headers = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&headers, &"Content-Type".into(), &"application/json".into());
+        init.set_headers(&headers);
+
+        let failure = match jobs::fetch(&url, init).await {
+            Ok((status, _)) if (200..300).contains(&status) => {
+                return "<span style='color: #0f0'>✅ Message sent. Thanks for reaching out!</span>".to_string();
+            }
+            Ok((status, _)) => format!("the server answered HTTP {}", status),
+            Err(err) => err.as_string().unwrap_or_else(|| "request failed (network or CORS)".to_string()),
+        };
+        let mailto = mailto_url(&message);
+        format!(
+            "<span style='color: #f44'>mail: could not send: {}</span>\n<span style='color: #888'>Your message is still here:</span> {}",
+            escape_html(&failure), output::link(&mailto, "✉️  send it from your mail client")
+        )
+    };
+    term.run_job("mail: sending message", task.boxed_local());
+}
@@ -1,493 +1,406 @@
-#![no_main]
+#![cfg_attr(not(test), no_main)]
+
+mod browser;
+mod content;
+mod editor;
+mod files;
+mod github;
+mod i18n;
+mod jobs;
+mod layout;
+mod mail;
+mod mux;
+mod output;
+mod projects;
+mod prompt;
+mod rc;
+mod record;
+mod resume;
+mod script;
+mod search;
+mod settings;
+mod skills;
+mod stream;
+mod system;
+mod terminal;
+mod timeline;
+mod tour;
+mod vfs;
 
-use wasm_bindgen::prelude::*;
-use web_sys::{window, HtmlInputElement, KeyboardEvent};
 use std::cell::RefCell;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+use web_sys::{window, Element};
+
+use content::{ACTIVITIES, CERTIFICATIONS, EDUCATION, PROFILE};
+use mux::Workspace;
+use terminal::Terminal;
 
 thread_local! {
-    static HISTORY: RefCell<Vec<String>> = RefCell::new(Vec::new());
-    static HISTORY_INDEX: RefCell<isize> = RefCell::new(-1);
+    static WORKSPACE: RefCell<Option<Rc<Workspace>>> = const { RefCell::new(None) };
+    static TERMINALS: RefCell<Vec<Rc<Terminal>>> = const { RefCell::new(Vec::new()) };
 }
 
 #[wasm_bindgen]
 pub fn init_terminal() {
-    print_welcome();
-}
-
-#[wasm_bindgen(start)]
-pub fn main() {
-    if let Some(input) = get_input() {
-        let _ = input.focus();
-        
-        let input_for_closure = input.clone();
-        let closure = Closure::wrap(Box::new(move |event: KeyboardEvent| {
-            match event.key().as_str() {
-                "Enter" => handle_command(&input_for_closure),
-                "ArrowUp" => {
-                    event.prevent_default();
-                    navigate_history(&input_for_closure, 1);
-                }
-                "ArrowDown" => {
-                    event.prevent_default();
-                    navigate_history(&input_for_closure, -1);
-                }
-                "Tab" => {
-                    event.prevent_default();
-                    autocomplete(&input_for_closure);
-                }
-                _ => {}
-            }
-        }) as Box<dyn FnMut(KeyboardEvent)>);
-        
-        input.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())
-            .expect("Failed to add keydown listener");
-        closure.forget();
-    }
+    WORKSPACE.with(|w| {
+        if let Some(term) = w.borrow().as_ref().and_then(|ws| ws.active()) {
+            term.focus();
+        }
+    });
 }
 
-fn get_input() -> Option<HtmlInputElement> {
-    window()
-        .and_then(|w| w.document())
-        .and_then(|d| d.get_element_by_id("command-input"))
-        .and_then(|e| e.dyn_into::<HtmlInputElement>().ok())
+/// Mounts an additional, independent terminal into `container`.
+#[wasm_bindgen]
+pub fn mount_terminal(container: &Element) -> Result<(), JsValue> {
+    let term = Terminal::mount(container)?;
+    term.start();
+    TERMINALS.with(|t| t.borrow_mut().push(term));
+    Ok(())
 }
 
-fn get_output() -> Option<web_sys::HtmlElement> {
-    window()
+#[wasm_bindgen(start)]
+pub fn main() {
+    i18n::init();
+    search::init();
+    let container = window()
         .and_then(|w| w.document())
-        .and_then(|d| d.get_element_by_id("output"))
-        .and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
-}
+        .and_then(|d| d.get_element_by_id("terminal"));
 
-fn print_welcome() {
-    print_output_colored("
-<span style='color: #0ff; font-size: 1.2em'>╔══════════════════════════════════════════════════╗</span>
-<span style='color: #0ff; font-size: 1.2em'>║</span>   <span style='color: #ffc600; font-weight: bold'>Terminal Portfolio - Ashish Kumar</span>      <span style='color: #0ff; font-size: 1.2em'>║</span>
-<span style='color: #0ff; font-size: 1.2em'>╚══════════════════════════════════════════════════╝</span>
-
-<span style='color: #0f0'>👋 Welcome! Type 'help' to see available commands</span>
-");
-}
-
-fn handle_command(input: &HtmlInputElement) {
-    let command = input.value().trim().to_string();
-    
-    if !command.is_empty() {
-        HISTORY.with(|h| {
-            let mut history = h.borrow_mut();
-            history.push(command.clone());
-        });
-        HISTORY_INDEX.with(|idx| {
-            let mut index = idx.borrow_mut();
-            *index = HISTORY.with(|h| h.borrow().len()) as isize;
-        });
-    }
-    
-    print_output(&format!("<span style='color: #0f0'>ashish@portfolio:~$</span> {}", command));
-    
-    let response = process_command(&command);
-    if !response.is_empty() {
-        print_output_colored(&response);
+    if let Some(container) = container {
+        let workspace = Workspace::mount(&container).expect("Failed to mount terminal");
+        WORKSPACE.with(|w| *w.borrow_mut() = Some(workspace));
     }
-    
-    input.set_value("");
-    let _ = input.focus();
-    scroll_to_bottom();
 }
 
-fn navigate_history(input: &HtmlInputElement, direction: isize) {
-    HISTORY.with(|h| {
-        let history = h.borrow();
-        if history.is_empty() { return; }
-        
-        HISTORY_INDEX.with(|idx| {
-            let mut index = idx.borrow_mut();
-            *index += direction;
-            
-            if *index < 0 {
-                *index = -1;
-                input.set_value("");
-            } else if (*index as usize) >= history.len() {
-                *index = (history.len() - 1) as isize;
-                input.set_value(&history[*index as usize]);
-            } else {
-                input.set_value(&history[*index as usize]);
-            }
-        });
-    });
-}
-
-fn autocomplete(input: &HtmlInputElement) {
-    let current = input.value();
-    let commands = vec!["help", "whoami", "skills", "education", "projects", "contact", "neofetch", "clear", "echo", "certifications"];
-    
-    for cmd in commands {
-        if cmd.starts_with(&current) {
-            input.set_value(cmd);
-            break;
+/// Runs a typed command line: `> file` redirection, then the command split
+/// into words and expanded as a script line would be.
+fn process_command(term: &Terminal, cmd: &str) -> Result<String, String> {
+    match files::split_redirect(cmd) {
+        Some(Ok(redirect)) => {
+            let target = script::expand(term, redirect.target).join(" ");
+            let output = process_command(term, redirect.command);
+            return files::redirect(term, &files::Redirect { target: &target, ..redirect }, output);
         }
+        Some(Err(error)) => return Err(error),
+        None => {}
     }
+    let words = script::expand(term, cmd);
+    process_words(term, &words)
 }
 
-fn print_output(text: &str) {
-    if let Some(output) = get_output() {
-        let current = output.inner_html();
-        output.set_inner_html(&format!("{}<div>{}</div>", current, text));
-    }
-}
-
-fn print_output_colored(html: &str) {
-    if let Some(output) = get_output() {
-        let current = output.inner_html();
-        output.set_inner_html(&format!("{}<div>{}</div>", current, html));
-    }
-}
-
-fn scroll_to_bottom() {
-    if let Some(output) = get_output() {
-        output.set_scroll_top(output.scroll_height());
-    }
-}
-
-fn process_command(cmd: &str) -> String {
-    let parts: Vec<&str> = cmd.split_whitespace().collect();
+/// Runs a command already split into words and expanded, as `sh` does for
+/// each line of a script. `Err` means the command failed; both sides hold
+/// the output to print.
+fn process_words(term: &Terminal, words: &[String]) -> Result<String, String> {
+    let parts: Vec<&str> = words.iter().map(String::as_str).collect();
     let command = parts.first().map(|s| s.to_lowercase()).unwrap_or_default();
     
     match command.as_str() {
-        "help" => get_help(),
-        "whoami" => get_whoami(),
-        "skills" => get_skills(),
-        "education" => get_education(),
-        "projects" | "ls" => get_projects(),
-        "contact" => get_contact(),
-        "certifications" => get_certifications(),
-        "neofetch" => get_neofetch(),
+        "help" => Ok(get_help()),
+        "whoami" => Ok(get_whoami()),
+        "skills" => skills::skills_command(&parts[1..]),
+        "education" => Ok(get_education()),
+        "ls" => files::ls_command(term, &parts[1..]),
+        "projects" => projects::projects_command(&parts[1..]),
+        "contact" => match parts[1..] {
+            ["--send"] => mail::mail_command(term, &[]),
+            _ => Ok(get_contact()),
+        },
+        "mail" => mail::mail_command(term, &parts[1..]),
+        "certifications" => Ok(get_certifications()),
+        "neofetch" => Ok(system::neofetch_command(term)),
+        "version" => Ok(system::version_command()),
+        "uname" => system::uname_command(&parts[1..]),
         "clear" => {
-            if let Some(output) = get_output() {
-                output.set_inner_html("");
-            }
-            print_welcome();
-            String::new()
+            term.clear();
+            term.print_welcome();
+            Ok(String::new())
         }
-        "echo" => {
-            if parts.len() > 1 {
-                parts[1..].join(" ")
-            } else {
-                "<span style='color: #888'>Usage: echo [text]</span>".to_string()
+        "pwd" => Ok(term.var("PWD").unwrap_or_default()),
+        "env" => Ok(term.vars().iter()
+            .map(|(k, v)| format!("<span style='color: #0ff'>{}</span>={}", k, v))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        "export" => {
+            if parts.len() == 1 {
+                return Err("<span style='color: #888'>Usage: export NAME[=value]...</span>".to_string());
+            }
+            let mut errors = Vec::new();
+            for word in &parts[1..] {
+                // A bare NAME is exported with the value it already has.
+                let (name, value) = match word.split_once('=') {
+                    Some((name, value)) => (name, value.to_string()),
+                    None => (*word, term.var(word).unwrap_or_default()),
+                };
+                if script::is_name(name) {
+                    term.set_var(name, &value);
+                } else {
+                    errors.push(format!("<span style='color: #f44'>export: '{}': not a valid identifier</span>", browser::escape_html(name)));
+                }
             }
+            if errors.is_empty() { Ok(String::new()) } else { Err(errors.join("\n")) }
         }
-        "cat" => {
+        "record" => record::record_command(term, &parts[1..]),
+        "replay" => record::replay_command(term, &parts[1..]),
+        "tour" => tour::tour_command(term, &parts[1..]),
+        "set" => settings::set_command(term, &parts[1..]),
+        "gh" => github::gh_command(&parts[1..]),
+        "resume" => resume::resume_command(&parts[1..]),
+        "open" => open_command(term, &parts[1..]),
+        "search" => search::search_command(&parts[1..]),
+        "timeline" => timeline::timeline_command(&parts[1..]),
+        "jobs" => Ok(jobs::jobs_command(term)),
+        "fg" => jobs::fg_command(term, &parts[1..]),
+        "kill" => jobs::kill_command(term, &parts[1..]),
+        "echo" => {
             if parts.len() > 1 {
-                match parts[1] {
-                    "projects/ids" => get_project_detail("ids"),
-                    "projects/ransomware" => get_project_detail("ransomware"),
-                    "projects/traffic" => get_project_detail("traffic"),
-                    "projects/password" => get_project_detail("password"),
-                    _ => format!("<span style='color: #f44'>File not found: {}</span>\n<span style='color: #888'>Try: cat projects/ids</span>", parts[1])
-                }
+                Ok(parts[1..].join(" "))
             } else {
-                "<span style='color: #888'>Usage: cat [file]</span>".to_string()
+                Ok("<span style='color: #888'>Usage: echo [text]</span>".to_string())
             }
         }
-        _ => format!("<span style='color: #f44'>❌ Command not found: '{}'</span>\n<span style='color: #888'>Type 'help' for available commands</span>", cmd)
+        "cat" => files::cat_command(term, &parts[1..]),
+        "cd" => files::cd_command(term, &parts[1..]),
+        "touch" => files::touch_command(term, &parts[1..]),
+        "mkdir" => files::mkdir_command(term, &parts[1..]),
+        "rm" => files::rm_command(term, &parts[1..]),
+        "mv" => files::mv_command(term, &parts[1..]),
+        "cp" => files::cp_command(term, &parts[1..]),
+        "sh" | "bash" => script::sh_command(term, &parts[1..]),
+        "alias" => rc::alias_command(term, &parts[1..]),
+        "unalias" => rc::unalias_command(term, &parts[1..]),
+        "lang" => i18n::lang_command(&parts[1..]),
+        "nano" | "vi" | "vim" => editor::nano_command(term, &command, &parts[1..]),
+        _ => Err(format!(
+            "<span style='color: #f44'>{}</span>\n<span style='color: #888'>{}</span>",
+            i18n::t_args("not-found", &[("command", &browser::escape_html(&parts.join(" ")))]), i18n::t("not-found-hint")
+        ))
     }
 }
 
+/// `help` rows: usage and the message id of its description.
+const HELP: &[(&str, &str)] = &[
+    ("help", "help-help"),
+    ("tour", "help-tour"),
+    ("whoami", "help-whoami"),
+    ("skills", "help-skills"),
+    ("education", "help-education"),
+    ("projects", "help-projects"),
+    ("cat [file]", "help-cat"),
+    ("ls / cd [dir]", "help-files"),
+    ("touch / mkdir / rm / mv / cp", "help-file-ops"),
+    ("[cmd] > file", "help-redirect"),
+    ("sh FILE", "help-sh"),
+    ("alias NAME=CMD", "help-alias"),
+    ("timeline", "help-timeline"),
+    ("certifications", "help-certifications"),
+    ("contact", "help-contact"),
+    ("mail", "help-mail"),
+    ("resume", "help-resume"),
+    ("search", "help-search"),
+    ("open", "help-open"),
+    ("gh repos", "help-gh"),
+    ("nano FILE", "help-nano"),
+    ("neofetch", "help-neofetch"),
+    ("version", "help-version"),
+    ("uname -a", "help-uname"),
+    ("clear", "help-clear"),
+    ("lang", "help-lang"),
+    ("set typing on|off", "help-typing"),
+    ("set theme NAME", "help-theme"),
+    ("curl [url]", "help-curl"),
+    ("sleep [seconds]", "help-sleep"),
+    ("[cmd] &", "help-background"),
+    ("jobs / fg / kill", "help-jobs"),
+    ("record", "help-record"),
+    ("replay", "help-replay"),
+];
+
+const HELP_KEYS: &[(&str, &str)] = &[
+    ("↑/↓", "help-key-history"),
+    ("Tab", "help-key-tab"),
+    ("Ctrl+B ?", "help-key-mux"),
+];
+
 fn get_help() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>              AVAILABLE COMMANDS</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #0ff'>help</span>              Show this help message
-<span style='color: #0ff'>whoami</span>            About me
-<span style='color: #0ff'>skills</span>            Technical skills
-<span style='color: #0ff'>education</span>         Educational background
-<span style='color: #0ff'>projects</span>          List projects
-<span style='color: #0ff'>cat [file]</span>        View project details
-<span style='color: #0ff'>certifications</span>    Certifications & courses
-<span style='color: #0ff'>contact</span>           Contact information
-<span style='color: #0ff'>neofetch</span>          System info
-<span style='color: #0ff'>clear</span>             Clear terminal
-
-<span style='color: #888'>Navigation:</span>
-<span style='color: #0f0'>↑/↓</span>               Command history
-<span style='color: #0f0'>Tab</span>               Auto-completion
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
-}
+    let table = |color: &str, rows: &[(&str, &str)]| {
+        let mut table = layout::Table::new();
+        for (usage, id) in rows {
+            table.row(vec![layout::Span::new(usage, &format!("color: {}", color)), layout::Span::plain(&i18n::t(id))]);
+        }
+        table.render()
+    };
+    format!("
+{}
 
-fn get_whoami() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>                    ABOUT ME</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #0ff'>👨‍ Name:</span>        Ashish Kumar
-<span style='color: #0ff'>🎓 Role:</span>         Cybersecurity Enthusiast & AI/ML Student
-<span style='color: #0ff'>📍 Location:</span>     Haryana, India
-<span style='color: #0ff'>🎯 Education:</span>    B.Tech CSE (AI/ML), GGSIPU
-<span style='color: #0ff'>📊 CGPA:</span>         8.7/10.0
-<span style='color: #0ff'>🎓 Year:</span>         Third Year (Expected May 2027)
-
-<span style='color: #888'>Third-year B.Tech student specializing in AI & ML,
-currently exploring cybersecurity fundamentals with
-hands-on experience in Linux, Python scripting, and
-security tools like Wireshark and Kali Linux.</span>
-
-<span style='color: #0ff'> Focus:</span>        Network Security, Threat Detection
-<span style='color: #0ff'>🚀 Seeking:</span>       Cybersecurity Internship
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
+{}
+
+<span style='color: #888'>{}</span>
+{}
+
+{}
+",
+        layout::header(&i18n::t("help-title")), table("#0ff", HELP),
+        i18n::t("help-navigation"), table("#0f0", HELP_KEYS), layout::footer()
+    )
 }
 
-fn get_skills() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>                TECHNICAL SKILLS</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #f90; font-weight: bold'>🔒 Cybersecurity:</span>
-   Network Security Fundamentals
-   Ethical Hacking Concepts
-   OWASP Top 10
-   Incident Response Awareness
-
-<span style='color: #f90; font-weight: bold'>🛠️ Security Tools:</span>
-   Wireshark     Nmap
-   Kali Linux    John the Ripper
-   Scapy         Burp Suite (basic)
-
-<span style='color: #f90; font-weight: bold'>💻 Programming:</span>
-   Python        Bash Scripting
-   SQL           Linux/Ubuntu
-
-<span style='color: #f90; font-weight: bold'>🌐 Networking:</span>
-   TCP/IP        HTTP/HTTPS
-   DNS           Firewalls
-
-<span style='color: #f90; font-weight: bold'>🤖 Machine Learning:</span>
-   Scikit-learn  Pandas
-   NumPy         Random Forest
-   SVM
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
+fn get_whoami() -> String {
+    let date = EDUCATION.graduates.to_string();
+    let label = |icon: &str, id: &str| format!("{} {}", icon, i18n::t(id));
+    let facts = layout::key_values("color: #0ff", vec![
+        (label("👤", "whoami-name"), layout::Span::plain(PROFILE.name)),
+        (label("🎓", "whoami-role"), layout::Span::plain(&i18n::t("whoami-role-value"))),
+        (label("📍", "whoami-location"), layout::Span::plain(PROFILE.location)),
+        (label("🎯", "whoami-education"), layout::Span::plain("B.Tech CSE (AI/ML), GGSIPU")),
+        (label("📊", "whoami-cgpa"), layout::Span::plain(EDUCATION.cgpa)),
+        (label("🎓", "whoami-year"), layout::Span::plain(&i18n::t_args("whoami-year-value", &[("date", &date)]))),
+    ]);
+    let goals = layout::key_values("color: #0ff", vec![
+        (label("🔍", "whoami-focus"), layout::Span::plain(&i18n::t("whoami-focus-value"))),
+        (label("🚀", "whoami-seeking"), layout::Span::plain(&i18n::t("whoami-seeking-value"))),
+    ]);
+    format!("
+{}
+
+{}
+
+<span style='color: #888'>{}</span>
+
+{}
+
+{}
+",
+        layout::header(&i18n::t("whoami-title")), facts,
+        browser::escape_html(&layout::reflow(&i18n::t("whoami-summary").replace('\n', " "), layout::width())), goals, layout::footer()
+    )
 }
 
 fn get_education() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>                  EDUCATION</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
+    let coursework: Vec<String> = EDUCATION.coursework.iter().map(|c| format!("   • {}", c)).collect();
+    format!("
+{}
 
-<span style='color: #0ff; font-weight: bold'>Bachelor of Technology (B.Tech)</span>
-Computer Science & Engineering with Specialization
-in Artificial Intelligence & Machine Learning
+<span style='color: #0ff; font-weight: bold'>{}</span>
+Computer Science &amp; Engineering with Specialization
+in Artificial Intelligence &amp; Machine Learning
 
-🏫 <span style='color: #ccc'>Guru Gobind Singh Indraprastha University</span>
-📍 New Delhi, India
-📅 Expected Graduation: May 2027
-📊 CGPA: 8.7/10.0
+🏫 <span style='color: #ccc'>{}</span>
+📍 {}
+📅 Graduation: {}
+📊 CGPA: {}
 
 <span style='color: #f90; font-weight: bold'>Relevant Coursework:</span>
-   • Computer Networks
-   • Data Structures & Algorithms
-   • Probability & Statistics
-   • Database Management Systems
-   • Cybersecurity Fundamentals
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
-}
-
-fn get_projects() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>                  PROJECTS</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
+{}
 
-<span style='color: #0f0'>📁 projects/</span>
-   ├── <span style='color: #0ff'>ids</span>                 - Network Intrusion Detection
-   ├── <span style='color: #0ff'>ransomware</span>          - Ransomware Simulation
-   ├── <span style='color: #0ff'>traffic</span>             - Network Traffic Analyzer
-   └── <span style='color: #0ff'>password</span>            - Password Security Analysis
+{}
+",
+        layout::header("EDUCATION"), EDUCATION.degree, EDUCATION.institution, EDUCATION.location,
+        EDUCATION.graduation(), EDUCATION.cgpa, browser::escape_html(&coursework.join("\n")), layout::footer()
+    )
+}
 
-<span style='color: #888'>💡 Use 'cat projects/[name]' to view details</span>
-<span style='color: #888'>   Example: cat projects/ids</span>
+fn get_certifications() -> String {
+    let certifications: Vec<String> = CERTIFICATIONS.iter()
+        .map(|c| format!(
+            "<span style='color: #0ff'>{} {}</span>\n   {} ({})",
+            if c.year.is_some() { "✅" } else { "🔄" }, c.name, c.issuer, c.status()
+        ))
+        .collect();
+    let activities: Vec<String> = ACTIVITIES.iter().map(|a| format!("   • {}", browser::escape_html(a))).collect();
+    format!("
+{}
+
+{}
 
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
-}
+<span style='color: #f90; font-weight: bold'>Learning Activities:</span>
+{}
 
-fn get_project_detail(project: &str) -> String {
-    match project {
-        "ids" => "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>      NETWORK INTRUSION DETECTION SYSTEM</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #888'>📅 Duration:</span> Mar 2024 - Apr 2024
-
-<span style='color: #888'>Built a basic intrusion detection system to identify
-network threats using machine learning algorithms.</span>
-
-<span style='color: #f90; font-weight: bold'>Key Features:</span>
-   • Worked with NSL-KDD dataset
-   • Implemented Random Forest & SVM classifiers
-   • Used Scikit-learn for ML implementation
-   • Feature engineering & model evaluation
-   • Identified DoS and probing attacks
-
-<span style='color: #f90; font-weight: bold'>Tech Stack:</span>
-   Python   Scikit-learn   ML
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string(),
-        "ransomware" => "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>        RANSOMWARE SIMULATION (EDUCATIONAL)</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #888'>📅 Duration:</span> May 2024 - Jun 2024
-
-<span style='color: #888'>Created a safe, non-malicious simulation to
-understand how ransomware works technically.</span>
-
-<span style='color: #f90; font-weight: bold'>Learning Outcomes:</span>
-   • File encryption concepts
-   • Prevention methods (backups, endpoint security)
-   • Developed in isolated environments
-   • Incident response procedures
-   • Cybersecurity measures importance
-
-<span style='color: #f90; font-weight: bold'>Tech Stack:</span>
-   Python   Encryption   Security Research
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string(),
-        "traffic" => "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>         NETWORK TRAFFIC ANALYSIS TOOL</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #888'>📅 Duration:</span> Feb 2024 - Mar 2024
-
-<span style='color: #888'>Developed a simple packet analyzer using Python
-and Scapy to capture network traffic.</span>
-
-<span style='color: #f90; font-weight: bold'>Key Features:</span>
-   • Packet capture using Scapy
-   • Protocol identification
-   • Network communication pattern analysis
-   • Comparison with Wireshark
-   • Deep understanding of packet structures
-
-<span style='color: #f90; font-weight: bold'>Tech Stack:</span>
-   Python   Scapy   Wireshark
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string(),
-        "password" => "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>         PASSWORD SECURITY ANALYSIS</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #888'>📅 Duration:</span> Jan 2024 - Feb 2024
-
-<span style='color: #888'>Experimented with John the Ripper to understand
-password cracking techniques.</span>
-
-<span style='color: #f90; font-weight: bold'>Learning Outcomes:</span>
-   • Password cracking techniques
-   • Strong password practices
-   • Security measures (salting & hashing)
-   • Authentication system vulnerabilities
-   • Common password weaknesses
-
-<span style='color: #f90; font-weight: bold'>Tech Stack:</span>
-   John the Ripper   Security   Analysis
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string(),
-        _ => "<span style='color: #f44'>Project not found</span>".to_string()
-    }
+{}
+",
+        layout::header("CERTIFICATIONS & COURSES"), certifications.join("\n\n"), activities.join("\n"), layout::footer()
+    )
 }
 
-fn get_certifications() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>             CERTIFICATIONS & COURSES</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
+const CONTACT_NOTE: &str = "Seeking cybersecurity internship opportunities to apply theoretical knowledge and gain practical experience in threat detection and security analysis.";
 
-<span style='color: #0ff'>✅ Introduction to Cybersecurity</span>
-   Cisco Networking Academy (2023)
+fn get_contact() -> String {
+    let link = |href: &str, label: &str| layout::Span::html(output::link(href, label), label);
+    let rows = [
+        ("#0ff", "📧 Email:", link(&format!("mailto:{}", PROFILE.email), PROFILE.email)),
+        ("#0ff", "📱 Phone:", layout::Span::plain("[Your Phone]")),
+        ("#0af", "💼 LinkedIn:", link(&format!("https://{}", PROFILE.linkedin), PROFILE.linkedin)),
+        ("#fff", "🐙 GitHub:", layout::Span::html(
+            format!("{}  <span style='color: #888'>(try 'gh repos')</span>", output::link(&format!("https://{}", PROFILE.github), PROFILE.github)),
+            PROFILE.github,
+        )),
+        ("#ccc", "📍 Location:", layout::Span::plain(PROFILE.location)),
+    ];
+    let mut table = layout::Table::new().gap(1);
+    for (color, label, value) in rows {
+        table.row(vec![layout::Span::new(label, &format!("color: {}", color)), value]);
+    }
+    format!("
+{}
 
-<span style='color: #0ff'>✅ Introduction to Python Programming</span>
-   Cisco Networking Academy (2023)
+{}
 
-<span style='color: #0ff'>✅ Artificial Intelligence Fundamentals</span>
-   IBM (2023)
+<span style='color: #888'>{}</span>
 
-<span style='color: #0ff'>🔄 Machine Learning</span>
-   Colombia Plus University (In Progress)
+<span style='color: #888'>💡 Shift+Tab to reach the links, or: open linkedin | github | email</span>
+<span style='color: #888'>✉️  Or write to me right here: contact --send</span>
+{}
+",
+        layout::header("CONTACT INFO"), table.render(), layout::reflow(CONTACT_NOTE, layout::width() - 2), layout::footer()
+    )
+}
 
-<span style='color: #0ff'>🔄 Networking Basics</span>
-   Cisco Networking Academy (In Progress)
+/// Where each `open` target points.
+fn open_target(name: &str) -> Option<String> {
+    match name {
+        "linkedin" => Some(format!("https://{}", PROFILE.linkedin)),
+        "github" => Some(format!("https://{}", PROFILE.github)),
+        "email" | "mail" => Some(format!("mailto:{}", PROFILE.email)),
+        "resume" => Some(resume::PDF.to_string()),
+        _ => None,
+    }
+}
 
-<span style='color: #f90; font-weight: bold'>Learning Activities:</span>
-   • Beginner-level CTF challenges
-   • TryHackMe practical labs
-   • Following cybersecurity news & research
-   • Personal security projects
+const OPEN_TARGETS: [&str; 4] = ["linkedin", "github", "email", "resume"];
 
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
+fn open_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    let name = match args {
+        [] => {
+            let term = term.rc();
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Some(choice) = prompt::select(&term, "Open which link?", &OPEN_TARGETS).await {
+                    let response = open_named(OPEN_TARGETS[choice]).unwrap_or_else(|e| e);
+                    term.print_output_colored(&response);
+                }
+            });
+            return Ok(String::new());
+        }
+        [name] => name.to_lowercase(),
+        _ => return Err("<span style='color: #888'>Usage: open [linkedin | github | email | resume]</span>".to_string()),
+    };
+    open_named(&name)
 }
 
-fn get_contact() -> String {
-    "
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-<span style='color: #fff; font-weight: bold'>                 CONTACT INFO</span>
-<span style='color: #ffc600; font-weight: bold'>═══════════════════════════════════════════════════</span>
-
-<span style='color: #0ff'>📧 Email:</span>      moryarudra009gmail.com
-<span style='color: #0ff'>📱 Phone:</span>      [Your Phone]
-<span style='color: #0af'>💼 LinkedIn:</span>  linkedin.com/in/rudramorye
-<span style='color: #fff'>🐙 GitHub:</span>    github.com/ashishkumar0724
-<span style='color: #ccc'>📍 Location:</span>  Haryana, India
-
-<span style='color: #888'>Seeking cybersecurity internship opportunities
-to apply theoretical knowledge and gain practical
-experience in threat detection and security analysis.</span>
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
+fn open_named(name: &str) -> Result<String, String> {
+    let Some(url) = open_target(name) else {
+        return Err(format!(
+            "<span style='color: #f44'>open: unknown target '{}'</span>\n<span style='color: #888'>Targets: {}</span>",
+            browser::escape_html(name), OPEN_TARGETS.join(", ")
+        ));
+    };
+    match browser::open_url(&url) {
+        Ok(()) => Ok(format!("<span style='color: #0f0'>Opening {}</span>", output::link(&url, &url))),
+        Err(err) => Err(format!(
+            "<span style='color: #f44'>open: {}</span>\n<span style='color: #888'>Follow the link instead: {}</span>",
+            browser::escape_html(&err.as_string().unwrap_or_else(|| "could not open a new tab".to_string())),
+            output::link(&url, &url)
+        )),
+    }
 }
-
-fn get_neofetch() -> String {
-    "
-<span style='color: #ffc600'>
-    █████╗ ██╗   ██╗███████╗████████╗███████╗███╗   ███╗
-   ██╔══██╗██║   ██║██╔════╝╚══██╔══╝██╔════╝████╗ ████║
-   ███████║██║   ██║███████╗   ██║   █████╗  ██╔██████║
-   ██╔══██║██║   ██║╚════██║   ██║   ██╔══╝  ██║╚██╔╝██║
-   ██║  ██║╚██████╝███████║   ██║   ███████╗██║ ╚═╝ ██║
-   ╚═╝  ╚═╝ ╚═════╝ ╚══════╝   ╚═╝   ╚══════╝╚═╝     ╚═╝
-</span>
-<span style='color: #0ff'>ashish@portfolio</span>
-─────────────────────────────────────────────────
-<span style='color: #f90'>OS</span>           Portfolio OS 2.0
-<span style='color: #f90'>Host</span>         Web Browser (Chrome/Firefox)
-<span style='color: #f90'>Kernel</span>       Rust + WebAssembly
-<span style='color: #f90'>Shell</span>        terminal-portfolio v2.0
-<span style='color: #f90'>Theme</span>        Dark (Cobalt2-inspired)
-<span style='color: #f90'>Education</span>    B.Tech CSE (AI/ML) - 3rd Year
-<span style='color: #f90'>CGPA</span>         8.7/10.0
-<span style='color: #f90'>Focus</span>        Cybersecurity & Network Security
-
-<span style='color: #ffc600'>═══════════════════════════════════════════════════</span>
-".to_string()
-}
\ No newline at end of file
@@ -0,0 +1,393 @@
+//! The [`Workspace`] the page mounts: tabs and split panes driven by
+//! `Ctrl+B` key bindings, which `Ctrl+B ?` lists.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::prelude::*;
+use web_sys::{window, Document, Element, HtmlElement, KeyboardEvent, MouseEvent};
+
+use crate::terminal::{KeyHook, Terminal};
+
+type ClickHandler = Closure<dyn FnMut(MouseEvent)>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Split {
+    /// Panes side by side (`Ctrl+B %`).
+    Vertical,
+    /// Panes stacked (`Ctrl+B "`).
+    Horizontal,
+}
+
+impl Split {
+    fn class(self) -> &'static str {
+        match self {
+            Split::Vertical => "mux-split vertical",
+            Split::Horizontal => "mux-split horizontal",
+        }
+    }
+}
+
+struct Pane {
+    element: HtmlElement,
+    term: Rc<Terminal>,
+    _click: ClickHandler,
+}
+
+struct Tab {
+    element: HtmlElement,
+    panes: Vec<Pane>,
+    active: usize,
+}
+
+/// tmux-style tabs and split panes. Every pane is an independent
+/// [`Terminal`]; the DOM nesting of `.mux-split` divs is the layout tree.
+pub struct Workspace {
+    me: Weak<Workspace>,
+    document: Document,
+    tab_bar: HtmlElement,
+    windows: HtmlElement,
+    tabs: RefCell<Vec<Tab>>,
+    active_tab: Cell<usize>,
+    prefix: Cell<bool>,
+    tab_click: ClickHandler,
+}
+
+impl Workspace {
+    pub fn mount(container: &Element) -> Result<Rc<Workspace>, JsValue> {
+        let document = window()
+            .and_then(|w| w.document())
+            .ok_or_else(|| JsValue::from_str("no document"))?;
+
+        let root = document.create_element("div")?;
+        root.set_class_name("mux");
+        let tab_bar: HtmlElement = document.create_element("div")?.dyn_into()?;
+        tab_bar.set_class_name("mux-tabs");
+        let windows: HtmlElement = document.create_element("div")?.dyn_into()?;
+        windows.set_class_name("mux-windows");
+        root.append_child(&tab_bar)?;
+        root.append_child(&windows)?;
+        container.append_child(&root)?;
+
+        let ws = Rc::new_cyclic(|me: &Weak<Workspace>| {
+            let weak = me.clone();
+            let tab_click = Closure::wrap(Box::new(move |event: MouseEvent| {
+                let index = event.target()
+                    .and_then(|t| t.dyn_into::<Element>().ok())
+                    .and_then(|e| e.get_attribute("data-tab"))
+                    .and_then(|i| i.parse::<usize>().ok());
+                if let (Some(ws), Some(index)) = (weak.upgrade(), index) {
+                    ws.select_tab(index);
+                }
+            }) as Box<dyn FnMut(MouseEvent)>);
+
+            Workspace {
+                me: me.clone(),
+                document,
+                tab_bar,
+                windows,
+                tabs: RefCell::new(Vec::new()),
+                active_tab: Cell::new(0),
+                prefix: Cell::new(false),
+                tab_click,
+            }
+        });
+        ws.tab_bar
+            .add_event_listener_with_callback("click", ws.tab_click.as_ref().unchecked_ref())?;
+
+        ws.new_tab()?;
+        Ok(ws)
+    }
+
+    /// The terminal in the focused pane of the current tab.
+    pub fn active(&self) -> Option<Rc<Terminal>> {
+        let tabs = self.tabs.borrow();
+        tabs.get(self.active_tab.get())
+            .and_then(|tab| tab.panes.get(tab.active))
+            .map(|pane| pane.term.clone())
+    }
+
+    fn new_pane(&self) -> Result<Pane, JsValue> {
+        let element: HtmlElement = self.document.create_element("div")?.dyn_into()?;
+        element.set_class_name("mux-pane");
+
+        let term = Terminal::mount(&element)?;
+        let weak = self.me.clone();
+        let hook: KeyHook = Rc::new(move |event: &KeyboardEvent| {
+            weak.upgrade().is_some_and(|ws| ws.on_key(event))
+        });
+        term.set_key_hook(hook);
+
+        let weak = self.me.clone();
+        let target = element.clone();
+        let click = Closure::wrap(Box::new(move |_: MouseEvent| {
+            if let Some(ws) = weak.upgrade() {
+                ws.focus_element(&target);
+            }
+        }) as Box<dyn FnMut(MouseEvent)>);
+        element.add_event_listener_with_callback("click", click.as_ref().unchecked_ref())?;
+
+        Ok(Pane { element, term, _click: click })
+    }
+
+    fn new_tab(&self) -> Result<(), JsValue> {
+        let element: HtmlElement = self.document.create_element("div")?.dyn_into()?;
+        element.set_class_name("mux-window");
+        let pane = self.new_pane()?;
+        element.append_child(&pane.element)?;
+        self.windows.append_child(&element)?;
+        pane.term.start();
+
+        let index = {
+            let mut tabs = self.tabs.borrow_mut();
+            tabs.push(Tab { element, panes: vec![pane], active: 0 });
+            tabs.len() - 1
+        };
+        self.select_tab(index);
+        Ok(())
+    }
+
+    fn select_tab(&self, index: usize) {
+        if index >= self.tabs.borrow().len() {
+            return;
+        }
+        self.active_tab.set(index);
+        self.refresh();
+    }
+
+    fn cycle_tab(&self, step: isize) {
+        let count = self.tabs.borrow().len() as isize;
+        if count > 0 {
+            let next = (self.active_tab.get() as isize + step).rem_euclid(count);
+            self.select_tab(next as usize);
+        }
+    }
+
+    fn split(&self, split: Split) -> Result<(), JsValue> {
+        let pane = self.new_pane()?;
+        {
+            let mut tabs = self.tabs.borrow_mut();
+            let Some(tab) = tabs.get_mut(self.active_tab.get()) else { return Ok(()) };
+            let current = tab.panes[tab.active].element.clone();
+
+            let same_direction = current.parent_element()
+                .is_some_and(|p| p.class_name() == split.class());
+            if same_direction {
+                current.after_with_node_1(&pane.element)?;
+            } else {
+                let container = self.document.create_element("div")?;
+                container.set_class_name(split.class());
+                current.replace_with_with_node_1(&container)?;
+                container.append_child(&current)?;
+                container.append_child(&pane.element)?;
+            }
+
+            pane.term.start();
+            tab.active += 1;
+            tab.panes.insert(tab.active, pane);
+        }
+        self.refresh();
+        Ok(())
+    }
+
+    fn close_pane(&self) -> Result<(), JsValue> {
+        let closed = {
+            let mut tabs = self.tabs.borrow_mut();
+            let index = self.active_tab.get();
+            let Some(tab) = tabs.get_mut(index) else { return Ok(()) };
+            let pane = tab.panes.remove(tab.active);
+            let parent = pane.element.parent_element();
+            pane.element.remove();
+
+            // Collapse a split that is left with a single child.
+            if let Some(parent) = parent {
+                if parent.class_list().contains("mux-split") && parent.child_element_count() == 1 {
+                    if let Some(only) = parent.first_element_child() {
+                        parent.replace_with_with_node_1(&only)?;
+                    }
+                }
+            }
+
+            if tab.panes.is_empty() {
+                let tab = tabs.remove(index);
+                tab.element.remove();
+                if index >= tabs.len() && index > 0 {
+                    self.active_tab.set(index - 1);
+                }
+            } else {
+                tab.active = tab.active.min(tab.panes.len() - 1);
+            }
+            pane
+        };
+
+        // The pane's terminal is usually the one whose key handler got us
+        // here, so let it finish before it is dropped.
+        // Cancelling any prompt first lets a command waiting on it let go.
+        closed.term.clear_mode();
+        let deferred = Closure::once_into_js(move || drop(closed));
+        if let Some(w) = window() {
+            w.set_timeout_with_callback_and_timeout_and_arguments_0(deferred.unchecked_ref(), 0)?;
+        }
+
+        if self.tabs.borrow().is_empty() {
+            self.new_tab()?;
+        } else {
+            self.refresh();
+        }
+        Ok(())
+    }
+
+    fn close_tab(&self) -> Result<(), JsValue> {
+        let count = {
+            let tabs = self.tabs.borrow();
+            tabs.get(self.active_tab.get()).map_or(0, |tab| tab.panes.len())
+        };
+        for _ in 0..count {
+            self.close_pane()?;
+        }
+        Ok(())
+    }
+
+    fn cycle_pane(&self) {
+        {
+            let mut tabs = self.tabs.borrow_mut();
+            if let Some(tab) = tabs.get_mut(self.active_tab.get()) {
+                tab.active = (tab.active + 1) % tab.panes.len();
+            }
+        }
+        self.refresh();
+    }
+
+    /// Moves focus to the nearest pane in the direction of `key`, judged by
+    /// on-screen position so it works for any nesting of splits.
+    fn move_focus(&self, key: &str) {
+        {
+            let mut tabs = self.tabs.borrow_mut();
+            let Some(tab) = tabs.get_mut(self.active_tab.get()) else { return };
+            let from = tab.panes[tab.active].element.get_bounding_client_rect();
+            let (fx, fy) = (from.x() + from.width() / 2.0, from.y() + from.height() / 2.0);
+
+            let mut best: Option<(usize, f64)> = None;
+            for (i, pane) in tab.panes.iter().enumerate() {
+                let r = pane.element.get_bounding_client_rect();
+                let (dx, dy) = (r.x() + r.width() / 2.0 - fx, r.y() + r.height() / 2.0 - fy);
+                let ahead = match key {
+                    "ArrowLeft" => dx < -1.0,
+                    "ArrowRight" => dx > 1.0,
+                    "ArrowUp" => dy < -1.0,
+                    _ => dy > 1.0,
+                };
+                let distance = dx * dx + dy * dy;
+                if ahead && best.is_none_or(|(_, d)| distance < d) {
+                    best = Some((i, distance));
+                }
+            }
+            if let Some((i, _)) = best {
+                tab.active = i;
+            }
+        }
+        self.refresh();
+    }
+
+    fn focus_element(&self, element: &HtmlElement) {
+        {
+            let mut tabs = self.tabs.borrow_mut();
+            let Some(tab) = tabs.get_mut(self.active_tab.get()) else { return };
+            match tab.panes.iter().position(|p| p.element.is_same_node(Some(element))) {
+                Some(i) if i != tab.active => tab.active = i,
+                _ => return,
+            }
+        }
+        self.refresh();
+    }
+
+    /// Re-renders the tab bar, shows the active window and focuses its pane.
+    fn refresh(&self) {
+        let tabs = self.tabs.borrow();
+        let active_tab = self.active_tab.get();
+
+        let labels: Vec<String> = tabs.iter().enumerate()
+            .map(|(i, tab)| {
+                let class = if i == active_tab { "mux-tab active" } else { "mux-tab" };
+                let panes = if tab.panes.len() > 1 { format!(" [{}]", tab.panes.len()) } else { String::new() };
+                format!("<span class='{}' data-tab='{}'>{}:portfolio{}</span>", class, i, i, panes)
+            })
+            .collect();
+        self.tab_bar.set_inner_html(&labels.join(""));
+
+        for (i, tab) in tabs.iter().enumerate() {
+            let _ = tab.element.class_list().toggle_with_force("active", i == active_tab);
+            for (j, pane) in tab.panes.iter().enumerate() {
+                let _ = pane.element.class_list().toggle_with_force("active", j == tab.active);
+            }
+        }
+
+        if let Some(pane) = tabs.get(active_tab).and_then(|tab| tab.panes.get(tab.active)) {
+            pane.term.focus();
+        }
+    }
+
+    fn on_key(&self, event: &KeyboardEvent) -> bool {
+        if event.ctrl_key() && event.key().eq_ignore_ascii_case("b") {
+            event.prevent_default();
+            self.prefix.set(true);
+            return true;
+        }
+        if !self.prefix.get() {
+            return false;
+        }
+        // Modifier keys on their own (e.g. Shift for `%`) keep the prefix armed.
+        if matches!(event.key().as_str(), "Shift" | "Control" | "Alt" | "Meta") {
+            return true;
+        }
+        self.prefix.set(false);
+        event.prevent_default();
+
+        let key = event.key();
+        let result = match key.as_str() {
+            "c" => self.new_tab(),
+            "n" => { self.cycle_tab(1); Ok(()) }
+            "p" => { self.cycle_tab(-1); Ok(()) }
+            "%" => self.split(Split::Vertical),
+            "\"" => self.split(Split::Horizontal),
+            "o" => { self.cycle_pane(); Ok(()) }
+            "x" => self.close_pane(),
+            "&" => self.close_tab(),
+            "ArrowLeft" | "ArrowRight" | "ArrowUp" | "ArrowDown" => { self.move_focus(&key); Ok(()) }
+            "?" => {
+                if let Some(term) = self.active() {
+                    term.print_output_colored(&get_bindings());
+                    term.scroll_to_bottom();
+                }
+                Ok(())
+            }
+            k => {
+                if let Ok(index) = k.parse::<usize>() {
+                    self.select_tab(index);
+                }
+                Ok(())
+            }
+        };
+        if let Err(err) = result {
+            web_sys::console::error_1(&err);
+        }
+        true
+    }
+}
+
+fn get_bindings() -> String {
+    "
+<span style='color: #ffc600; font-weight: bold'>Tabs &amp; panes</span> <span style='color: #888'>(press Ctrl+B, then the key)</span>
+
+<span style='color: #0ff'>c</span>                 New tab
+<span style='color: #0ff'>n / p</span>             Next / previous tab
+<span style='color: #0ff'>0-9</span>               Jump to tab
+<span style='color: #0ff'>&amp;</span>                 Close tab
+<span style='color: #0ff'>%</span>                 Split side by side
+<span style='color: #0ff'>\"</span>                 Split top / bottom
+<span style='color: #0ff'>o</span>                 Next pane
+<span style='color: #0ff'>←↑↓→</span>              Move to pane
+<span style='color: #0ff'>x</span>                 Close pane
+<span style='color: #0ff'>?</span>                 Show these bindings
+".to_string()
+}
@@ -0,0 +1,125 @@
+//! Richer spans for command output. Output is HTML; anything beyond a
+//! colored `<span>` gets its markup built here so it stays consistent.
+
+use std::fmt;
+
+use crate::browser::escape_html;
+
+/// A clickable hyperlink. It renders as a real `<a>`, so it can be reached
+/// with Shift+Tab from the prompt and followed with Enter.
+pub struct Link<'a> {
+    href: &'a str,
+    label: &'a str,
+}
+
+pub fn link<'a>(href: &'a str, label: &'a str) -> Link<'a> {
+    Link { href, label }
+}
+
+impl fmt::Display for Link<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // mailto: hands off to the mail client; anything else opens a new tab.
+        let target = if self.href.starts_with("mailto:") {
+            ""
+        } else {
+            " target='_blank' rel='noopener noreferrer'"
+        };
+        write!(
+            f,
+            "<a class='term-link' href='{}'{}>{}</a>",
+            escape_html(self.href), target, escape_html(self.label)
+        )
+    }
+}
+
+/// Escapes `text` and shows it highlighted, like a search match.
+pub fn mark(text: &str) -> String {
+    format!("<span style='background: #ffc600; color: #000'>{}</span>", escape_html(text))
+}
+
+/// What [`highlight`] marks.
+pub enum Match<'a> {
+    /// Every case-insensitive occurrence of the term.
+    Substring(&'a str),
+    /// Whole words starting with any of the (lowercase) terms.
+    WordPrefix(&'a [String]),
+}
+
+/// Byte ranges of the words in `text`.
+pub fn words(text: &str) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                out.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        out.push((s, text.len()));
+    }
+    out
+}
+
+/// Escapes `text` and marks what `matching` selects.
+pub fn highlight(text: &str, matching: Match) -> String {
+    let ranges: Vec<(usize, usize)> = match matching {
+        Match::Substring(term) => {
+            let term = term.to_ascii_lowercase();
+            if term.is_empty() {
+                return escape_html(text);
+            }
+            // ASCII lowercasing keeps byte offsets, so matches index into `text`.
+            text.to_ascii_lowercase()
+                .match_indices(&term)
+                .map(|(start, _)| (start, start + term.len()))
+                .collect()
+        }
+        Match::WordPrefix(terms) => words(text).into_iter()
+            .filter(|&(start, end)| {
+                let word = text[start..end].to_lowercase();
+                terms.iter().any(|t| word.starts_with(t.as_str()))
+            })
+            .collect(),
+    };
+    let mut out = String::new();
+    let mut rest = 0;
+    for (start, end) in ranges {
+        out.push_str(&escape_html(&text[rest..start]));
+        out.push_str(&mark(&text[start..end]));
+        rest = end;
+    }
+    out.push_str(&escape_html(&text[rest..]));
+    out
+}
+
+/// The text `html` shows: tags dropped and entities decoded, for writing
+/// command output to a file.
+pub fn plain_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// A command the visitor can run by clicking it (or focusing it and
+/// pressing Enter); the terminal handles clicks on `data-command`.
+pub fn command_link(command: &str) -> String {
+    let command = escape_html(command);
+    format!("<a class='term-link' href='#' data-command='{}'>{}</a>", command, command)
+}
@@ -0,0 +1,197 @@
+//! `projects` and `cat projects/<id>`: the project model as a directory
+//! listing, filterable by tag and date, plus a highlighted text search.
+
+use crate::browser::escape_html;
+use crate::content::{Project, YearMonth, PROJECTS};
+use crate::layout::{self, Span, Table};
+use crate::output::{self, Match};
+
+const USAGE: &str = "<span style='color: #888'>Usage: projects [--tag TAG] [--since YYYY-MM] | projects search TERM</span>";
+
+pub fn projects_command(args: &[&str]) -> Result<String, String> {
+    if let Some((&"search", terms)) = args.split_first() {
+        return search(&terms.join(" "));
+    }
+
+    let mut tag = None;
+    let mut since = None;
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        match (arg, args.next()) {
+            ("--tag" | "-t", Some(value)) => tag = Some(value.to_lowercase()),
+            ("--since", Some(value)) => match YearMonth::parse(value) {
+                Some(date) => since = Some(date),
+                None => return Err(format!(
+                    "<span style='color: #f44'>projects: '{}' is not a date (use YYYY-MM)</span>\n{}",
+                    escape_html(value), USAGE
+                )),
+            },
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let matching: Vec<&Project> = PROJECTS.iter()
+        .filter(|p| tag.as_deref().is_none_or(|t| has_tag(p, t)))
+        // Active at any point from `since` on, even if it started earlier.
+        .filter(|p| since.is_none_or(|since| p.dates.end >= since))
+        .collect();
+
+    let mut filters = Vec::new();
+    if let Some(tag) = &tag {
+        filters.push(format!("#{}", escape_html(tag)));
+    }
+    if let Some(since) = since {
+        filters.push(format!("since {}", since));
+    }
+    Ok(listing(&matching, &filters))
+}
+
+/// Tags and tech stack both count, case-insensitively.
+fn has_tag(project: &Project, tag: &str) -> bool {
+    project.tags.iter().chain(project.tech).any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+fn all_tags() -> Vec<&'static str> {
+    let mut tags: Vec<&str> = PROJECTS.iter().flat_map(|p| p.tags.iter().copied()).collect();
+    tags.sort_unstable();
+    tags.dedup();
+    tags
+}
+
+fn listing(projects: &[&Project], filters: &[String]) -> String {
+    let rows = if projects.is_empty() {
+        "   <span style='color: #888'>(no projects match)</span>".to_string()
+    } else {
+        let mut table = Table::new().indent(3).gap(1);
+        for (i, p) in projects.iter().enumerate() {
+            let branch = if i + 1 == projects.len() { "└──" } else { "├──" };
+            table.row(vec![
+                Span::plain(branch),
+                Span::new(p.id, "color: #0ff"),
+                Span::plain(&format!("- {}", p.tagline)),
+                Span::new(&p.dates.to_string(), "color: #888"),
+            ]);
+        }
+        table.render()
+    };
+    let filtered = if filters.is_empty() {
+        String::new()
+    } else {
+        format!(" <span style='color: #888'>({})</span>", filters.join(", "))
+    };
+
+    format!(
+        "
+{}
+
+<span style='color: #0f0'>📁 projects/</span>{}
+{}
+
+<span style='color: #888'>💡 Use 'cat projects/[name]' to view details</span>
+<span style='color: #888'>   Example: cat projects/ids</span>
+<span style='color: #888'>   Filter: projects --tag python · projects --since 2024-03 · projects search scapy</span>
+<span style='color: #888'>   Tags: {}</span>
+
+{}
+",
+        layout::header("PROJECTS"), filtered, rows, all_tags().join(", "), layout::footer()
+    )
+}
+
+/// A project as the Markdown file `~/projects/<id>` in the filesystem.
+pub fn project_markdown(project: &Project) -> String {
+    let mut out = format!("# {}\n\n*{}*\n\n{}\n\n## {}\n\n", project.title, project.dates, project.summary, project.highlights_label);
+    for highlight in project.highlights {
+        out.push_str(&format!("- {}\n", highlight));
+    }
+    out.push_str(&format!("\n## Tech Stack\n\n{}\n", project.tech.join(", ")));
+    if !project.links.is_empty() {
+        out.push_str("\n## Links\n\n");
+        for (label, url) in project.links {
+            out.push_str(&format!("- [{}]({})\n", label, url));
+        }
+    }
+    out
+}
+
+/// `cat projects/<id>`; `None` if there is no such project.
+pub fn project_detail(id: &str) -> Option<String> {
+    let project = PROJECTS.iter().find(|p| p.id == id)?;
+    let highlights: Vec<String> = project.highlights.iter().map(|h| format!("   • {}", escape_html(h))).collect();
+    let tags: Vec<String> = project.tags.iter().map(|t| format!("#{}", t)).collect();
+    let links: String = project.links.iter()
+        .map(|(label, url)| format!("\n   {}: {}", label, output::link(url, url)))
+        .collect();
+    let links = if links.is_empty() {
+        String::new()
+    } else {
+        format!("\n<span style='color: #f90; font-weight: bold'>Links:</span>{}\n", links)
+    };
+
+    Some(format!(
+        "
+{}
+
+<span style='color: #888'>📅 Duration:</span> {}
+
+<span style='color: #888'>{}</span>
+
+<span style='color: #f90; font-weight: bold'>{}:</span>
+{}
+
+<span style='color: #f90; font-weight: bold'>Tech Stack:</span>
+   {}
+{}
+<span style='color: #888'>Tags: {}</span>
+{}
+",
+        layout::header(&project.title.to_uppercase()), project.dates, escape_html(&layout::reflow(project.summary, layout::width())),
+        project.highlights_label, highlights.join("\n"), escape_html(&project.tech.join("   ")),
+        links, tags.join(" "), layout::footer()
+    ))
+}
+
+fn search(term: &str) -> Result<String, String> {
+    let term = term.trim();
+    if term.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let needle = term.to_ascii_lowercase();
+    let contains = |text: &str| text.to_ascii_lowercase().contains(&needle);
+
+    let results: Vec<String> = PROJECTS.iter()
+        .filter_map(|p| {
+            let lines: Vec<&str> = std::iter::once(p.summary)
+                .chain(p.highlights.iter().copied())
+                .filter(|line| contains(line))
+                .collect();
+            let tech: Vec<&str> = p.tech.iter().chain(p.tags).copied().filter(|t| contains(t)).collect();
+            if lines.is_empty() && tech.is_empty() && !contains(p.title) {
+                return None;
+            }
+            let mut out = format!(
+                "<span style='color: #0ff'>{}</span> <span style='color: #888'>(cat projects/{})</span>",
+                output::highlight(p.title, Match::Substring(term)), p.id
+            );
+            for line in lines {
+                out.push_str(&format!("\n   {}", output::highlight(line, Match::Substring(term))));
+            }
+            if !tech.is_empty() {
+                let tech: Vec<String> = tech.iter().map(|t| output::highlight(t, Match::Substring(term))).collect();
+                out.push_str(&format!("\n   <span style='color: #888'>tech/tags:</span> {}", tech.join(", ")));
+            }
+            Some(out)
+        })
+        .collect();
+
+    if results.is_empty() {
+        return Ok(format!(
+            "<span style='color: #888'>No projects mention '{}'.</span>",
+            escape_html(term)
+        ));
+    }
+    Ok(format!(
+        "<span style='color: #888'>{} project{} matching '{}':</span>\n\n{}",
+        results.len(), if results.len() == 1 { "" } else { "s" }, escape_html(term), results.join("\n\n")
+    ))
+}
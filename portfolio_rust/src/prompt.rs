@@ -0,0 +1,181 @@
+//! Prompts for interactive commands: `read_line`, `confirm` and `select`.
+//! Each one takes over the terminal's keyboard until it is answered and
+//! resolves to `None` if the visitor cancels with Esc or Ctrl+C.
+//!
+//! ```ignore
+//! let term = term.rc();
+//! spawn_local(async move {
+//!     if let Some(name) = prompt::read_line(&term, "Name:").await { ... }
+//! });
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use web_sys::KeyboardEvent;
+
+use crate::browser::escape_html;
+use crate::terminal::{KeyHook, Terminal};
+
+/// What a prompt made of a key press.
+enum Key<T> {
+    /// Not ours: let the input box have it (ordinary typing).
+    Pass,
+    /// Swallowed; the prompt is still waiting.
+    Eaten,
+    Answer(T),
+}
+
+/// Installs `on_key` as the terminal's mode and waits for an answer.
+async fn ask<T: 'static>(
+    term: &Terminal,
+    label: &str,
+    on_key: impl Fn(&Terminal, &KeyboardEvent) -> Key<T> + 'static,
+) -> Option<T> {
+    let (sender, receiver) = oneshot::channel();
+    let sender = RefCell::new(Some(sender));
+    let echo = escape_html(label);
+    let me = Rc::downgrade(&term.rc());
+
+    let hook: KeyHook = Rc::new(move |event: &KeyboardEvent| {
+        let Some(term) = me.upgrade() else { return false };
+        let cancelled = event.key() == "Escape" || (event.ctrl_key() && event.key() == "c");
+        let answer = if cancelled {
+            term.print_output(&format!("<span style='color: #0ff'>{}</span> <span style='color: #888'>^C</span>", echo));
+            None
+        } else {
+            match on_key(&term, event) {
+                Key::Pass => return false,
+                Key::Eaten => {
+                    event.prevent_default();
+                    return true;
+                }
+                Key::Answer(value) => Some(value),
+            }
+        };
+        event.prevent_default();
+        term.clear_mode();
+        term.set_input("");
+        term.reset_prompt();
+        term.scroll_to_bottom();
+        if let Some(sender) = sender.borrow_mut().take() {
+            let _ = sender.send(answer);
+        }
+        true
+    });
+
+    term.set_input("");
+    term.set_prompt(label);
+    term.set_mode(hook);
+    term.scroll_to_bottom();
+    // A dropped sender (the mode was replaced or cleared) also means "no answer".
+    receiver.await.ok().flatten()
+}
+
+fn echo(term: &Terminal, label: &str, answer: &str) {
+    term.print_output(&format!("<span style='color: #0ff'>{}</span> {}", escape_html(label), answer));
+}
+
+/// Reads one line of text.
+pub async fn read_line(term: &Terminal, label: &str) -> Option<String> {
+    let owned = label.to_string();
+    ask(term, label, move |term, event| match event.key().as_str() {
+        "Enter" => {
+            let value = term.input_value();
+            echo(term, &owned, &escape_html(&value));
+            Key::Answer(value)
+        }
+        // History and completion belong to the shell, not to the prompt.
+        "ArrowUp" | "ArrowDown" | "Tab" => Key::Eaten,
+        _ => Key::Pass,
+    })
+    .await
+}
+
+/// Asks a yes/no question; Enter takes `default`.
+pub async fn confirm(term: &Terminal, question: &str, default: bool) -> Option<bool> {
+    let label = format!("{} {}", question, if default { "[Y/n]" } else { "[y/N]" });
+    let owned = label.clone();
+    ask(term, &label, move |term, event| {
+        let answer = match event.key().as_str() {
+            "y" | "Y" => true,
+            "n" | "N" => false,
+            "Enter" => default,
+            // Only y, n and Enter mean anything here.
+            _ if event.ctrl_key() || event.meta_key() => return Key::Pass,
+            _ => return Key::Eaten,
+        };
+        echo(term, &owned, if answer { "yes" } else { "no" });
+        Key::Answer(answer)
+    })
+    .await
+}
+
+/// Lets the visitor pick one of `options` with the arrow keys (or its
+/// number) and Enter; resolves to the chosen index.
+pub async fn select(term: &Terminal, question: &str, options: &[&str]) -> Option<usize> {
+    if options.is_empty() {
+        return None;
+    }
+    let options: Vec<String> = options.iter().map(|o| o.to_string()).collect();
+    let render = {
+        let options = options.clone();
+        move |current: usize| {
+            let lines: Vec<String> = options.iter()
+                .enumerate()
+                .map(|(i, option)| if i == current {
+                    format!("<span style='color: #0f0'>❯ {}. {}</span>", i + 1, escape_html(option))
+                } else {
+                    format!("<span style='color: #888'>  {}. {}</span>", i + 1, escape_html(option))
+                })
+                .collect();
+            format!("{}\n<span style='color: #888'>↑/↓ to move, Enter to choose</span>", lines.join("\n"))
+        }
+    };
+
+    let list = term.print_live(&render(0));
+    let shown = list.clone();
+    let current = Cell::new(0);
+    let owned = question.to_string();
+
+    let chosen = ask(term, question, move |term, event| {
+        let count = options.len();
+        let key = event.key();
+        let moved = match key.as_str() {
+            "ArrowUp" | "k" => Some((current.get() + count - 1) % count),
+            "ArrowDown" | "j" | "Tab" => Some((current.get() + 1) % count),
+            _ => None,
+        };
+        if let Some(index) = moved {
+            current.set(index);
+            if let Some(list) = &list {
+                list.set_inner_html(&render(index));
+            }
+            return Key::Eaten;
+        }
+
+        let index = match key.as_str() {
+            "Enter" => current.get(),
+            digit => match digit.parse::<usize>() {
+                Ok(n) if (1..=count).contains(&n) => n - 1,
+                _ if event.ctrl_key() || event.meta_key() => return Key::Pass,
+                _ => return Key::Eaten,
+            },
+        };
+        // The list has served its purpose; the echo line records the choice.
+        if let Some(list) = &list {
+            list.remove();
+        }
+        echo(term, &owned, &escape_html(&options[index]));
+        Key::Answer(index)
+    })
+    .await;
+
+    if chosen.is_none() {
+        if let Some(list) = shown {
+            list.remove();
+        }
+    }
+    chosen
+}
@@ -0,0 +1,71 @@
+//! `~/.portfoliorc`, run by `sh` in every new terminal before the welcome
+//! banner, and the `alias` / `unalias` builtins it is mostly for. Loading
+//! the page with `?norc` skips it, for when an rc file breaks the shell.
+
+use crate::browser::{self, escape_html};
+use crate::script;
+use crate::terminal::Terminal;
+use crate::vfs::{self, HOME};
+
+pub const RC_FILE: &str = "~/.portfoliorc";
+
+/// URL query parameter that skips the rc file.
+const NORC: &str = "norc";
+
+/// Runs the rc file in `term`, if there is one; its output (mostly errors).
+pub fn run(term: &Terminal) -> String {
+    if browser::query_param(NORC).is_some() || !vfs::exists(&vfs::resolve(HOME, RC_FILE)) {
+        return String::new();
+    }
+    match script::sh_command(term, &[RC_FILE]) {
+        Ok(output) => output,
+        Err(output) => format!("{}\n<span style='color: #888'>Load the page with ?{} to skip {}</span>", output, NORC, RC_FILE),
+    }
+}
+
+fn is_alias_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || "_-.".contains(c))
+}
+
+fn show(name: &str, value: &str) -> String {
+    format!("alias <span style='color: #0ff'>{}</span>='{}'", escape_html(name), escape_html(value))
+}
+
+/// `alias`, `alias NAME` or `alias NAME=COMMAND...`.
+pub fn alias_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return Ok(term.aliases().iter().map(|(name, value)| show(name, value)).collect::<Vec<_>>().join("\n"));
+    }
+    let definition = args.join(" ");
+    match definition.split_once('=') {
+        Some((name, value)) if is_alias_name(name) => {
+            term.set_alias(name, value);
+            Ok(String::new())
+        }
+        Some((name, _)) => Err(format!("<span style='color: #f44'>alias: '{}': invalid alias name</span>", escape_html(name))),
+        None => {
+            let mut missing = false;
+            let lines: Vec<String> = args.iter()
+                .map(|name| match term.alias(name) {
+                    Some(value) => show(name, &value),
+                    None => {
+                        missing = true;
+                        format!("<span style='color: #f44'>alias: {}: not found</span>", escape_html(name))
+                    }
+                })
+                .collect();
+            if missing { Err(lines.join("\n")) } else { Ok(lines.join("\n")) }
+        }
+    }
+}
+
+pub fn unalias_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if args.is_empty() {
+        return Err("<span style='color: #888'>Usage: unalias NAME...</span>".to_string());
+    }
+    let missing: Vec<String> = args.iter()
+        .filter(|name| !term.remove_alias(name))
+        .map(|name| format!("<span style='color: #f44'>unalias: {}: not found</span>", escape_html(name)))
+        .collect();
+    if missing.is_empty() { Ok(String::new()) } else { Err(missing.join("\n")) }
+}
@@ -0,0 +1,349 @@
+//! Session recording (`record`) and playback (`replay`) in asciicast v2.
+
+use std::rc::Rc;
+
+use crate::browser;
+use crate::terminal::Terminal;
+
+/// Dimensions written into the asciicast header.
+const WIDTH: u32 = 100;
+const HEIGHT: u32 = 30;
+
+/// Pauses longer than this are shortened on replay, like `asciinema -i`.
+const IDLE_LIMIT: f64 = 2.0;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum EventKind {
+    Input,
+    Output,
+}
+
+impl EventKind {
+    fn code(self) -> &'static str {
+        match self {
+            EventKind::Input => "i",
+            EventKind::Output => "o",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Event {
+    time: f64,
+    kind: EventKind,
+    data: String,
+}
+
+#[derive(Clone)]
+pub struct Recording {
+    started: f64,
+    timestamp: u64,
+    events: Vec<Event>,
+}
+
+impl Recording {
+    pub fn new() -> Recording {
+        Recording { started: browser::now(), timestamp: browser::unix_time(), events: Vec::new() }
+    }
+
+    pub fn push(&mut self, kind: EventKind, data: String) {
+        let time = (browser::now() - self.started) / 1000.0;
+        self.events.push(Event { time, kind, data });
+    }
+
+    fn duration(&self) -> f64 {
+        self.events.last().map_or(0.0, |e| e.time)
+    }
+
+    /// Serialises the recording as an asciicast v2 file.
+    pub fn to_asciicast(&self, shell: &str, term: &str) -> String {
+        let mut cast = format!(
+            "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {}, \"title\": \"ashish@portfolio\", \"env\": {{\"SHELL\": {}, \"TERM\": {}}}}}\n",
+            WIDTH, HEIGHT, self.timestamp, json_string(shell), json_string(term)
+        );
+        for event in &self.events {
+            cast.push_str(&format!("[{:.6}, \"{}\", {}]\n", event.time, event.kind.code(), json_string(&event.data)));
+        }
+        cast
+    }
+}
+
+/// Maps a key press to the bytes a terminal would have sent for it.
+pub fn key_to_input(key: &str, ctrl: bool) -> Option<String> {
+    let mut chars = key.chars();
+    let single = match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    };
+    if ctrl {
+        return single
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| ((c.to_ascii_lowercase() as u8 - b'a' + 1) as char).to_string());
+    }
+    match key {
+        "Enter" => Some("\r".to_string()),
+        "Backspace" => Some("\u{7f}".to_string()),
+        "Tab" => Some("\t".to_string()),
+        "Escape" => Some("\u{1b}".to_string()),
+        "ArrowUp" => Some("\u{1b}[A".to_string()),
+        "ArrowDown" => Some("\u{1b}[B".to_string()),
+        "ArrowRight" => Some("\u{1b}[C".to_string()),
+        "ArrowLeft" => Some("\u{1b}[D".to_string()),
+        _ => single.map(String::from),
+    }
+}
+
+pub fn record_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match args.first().copied() {
+        Some("start") => {
+            if term.is_recording() {
+                return Err("<span style='color: #f90'>Already recording. Use 'record stop' to finish.</span>".to_string());
+            }
+            term.start_recording();
+            Ok("<span style='color: #f44'>●</span> Recording started. Use 'record stop' to finish.".to_string())
+        }
+        Some("stop") => match term.stop_recording() {
+            Some(rec) => Ok(format!(
+                "<span style='color: #0f0'>■ Recording stopped</span> ({} events, {:.1}s)\n<span style='color: #888'>Use 'replay' to play it back or 'record save' to download it.</span>",
+                rec.events.len(), rec.duration()
+            )),
+            None => Err("<span style='color: #f44'>Not recording.</span> <span style='color: #888'>Try: record start</span>".to_string()),
+        },
+        Some("save") => match term.last_recording() {
+            Some(rec) => {
+                let name = args.get(1).map_or("portfolio-session.cast".to_string(), |n| {
+                    if n.ends_with(".cast") { n.to_string() } else { format!("{}.cast", n) }
+                });
+                let cast = rec.to_asciicast(
+                    &term.var("SHELL").unwrap_or_default(),
+                    &term.var("TERM").unwrap_or_default(),
+                );
+                match browser::download(&name, "application/x-asciicast", &cast) {
+                    Ok(()) => Ok(format!("<span style='color: #0f0'>Saved {}</span>", browser::escape_html(&name))),
+                    Err(_) => Err("<span style='color: #f44'>Download failed</span>".to_string()),
+                }
+            }
+            None => Err("<span style='color: #f44'>Nothing to save.</span> <span style='color: #888'>Record a session first: record start</span>".to_string()),
+        },
+        Some("status") | None => {
+            if term.is_recording() {
+                Ok("<span style='color: #f44'>●</span> Recording in progress".to_string())
+            } else {
+                Err("<span style='color: #888'>Usage: record start | stop | save [name] | status</span>".to_string())
+            }
+        }
+        Some(other) => Err(format!("<span style='color: #f44'>record: unknown action '{}'</span>\n<span style='color: #888'>Usage: record start | stop | save [name] | status</span>", browser::escape_html(other))),
+    }
+}
+
+pub fn replay_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    let mut speed = 1.0;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match *arg {
+            "-s" | "--speed" => iter.next().copied(),
+            other => Some(other.trim_end_matches('x')),
+        };
+        match value.and_then(|v| v.parse::<f64>().ok()).filter(|s| *s > 0.0) {
+            Some(s) => speed = s,
+            None => return Err("<span style='color: #888'>Usage: replay [--speed N]</span>".to_string()),
+        }
+    }
+
+    if term.is_recording() {
+        return Err("<span style='color: #f90'>Stop the current recording first: record stop</span>".to_string());
+    }
+    let Some(rec) = term.last_recording() else {
+        return Err("<span style='color: #f44'>No recording yet.</span> <span style='color: #888'>Try: record start</span>".to_string());
+    };
+
+    term.stop_replay();
+    let me = Rc::downgrade(&term.rc());
+    let mut timers = Vec::new();
+    let mut elapsed = 0.0;
+    let mut previous = 0.0;
+    for event in rec.events.iter().filter(|e| e.kind == EventKind::Output) {
+        elapsed += (event.time - previous).min(IDLE_LIMIT);
+        previous = event.time;
+        let html = ansi_to_html(event.data.trim_end_matches("\r\n"));
+        let me = me.clone();
+        timers.push(browser::set_timeout(move || {
+            if let Some(term) = me.upgrade() {
+                term.print_output_colored(&html);
+                term.scroll_to_bottom();
+            }
+        }, (elapsed * 1000.0 / speed) as i32));
+    }
+    let done = me.clone();
+    timers.push(browser::set_timeout(move || {
+        if let Some(term) = done.upgrade() {
+            term.finish_replay();
+            term.print_output_colored("<span style='color: #888'>■ Replay finished</span>");
+            term.scroll_to_bottom();
+        }
+    }, (elapsed * 1000.0 / speed) as i32 + 1));
+    term.set_replay_timers(timers);
+
+    Ok(format!("<span style='color: #0ff'>▶ Replaying {:.1}s session at {}x</span> <span style='color: #888'>(Ctrl+C to stop)</span>", rec.duration(), speed))
+}
+
+/// Quotes `text` as a JSON string literal.
+pub fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Converts the output HTML (inline-styled spans) into ANSI-coloured text.
+pub fn html_to_ansi(html: &str) -> String {
+    let mut out = String::new();
+    let mut styles: Vec<String> = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&decode_entities(&rest[..start]));
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if tag.starts_with("span") {
+            let sgr = style_to_sgr(tag);
+            out.push_str(&sgr);
+            styles.push(sgr);
+        } else if tag == "/span" {
+            styles.pop();
+            out.push_str("\u{1b}[0m");
+            for s in &styles {
+                out.push_str(s);
+            }
+        } else if tag == "br" || tag == "/div" {
+            out.push('\n');
+        }
+    }
+    out.push_str(&decode_entities(rest));
+    out.replace('\n', "\r\n")
+}
+
+fn style_to_sgr(tag: &str) -> String {
+    let mut sgr = String::new();
+    if tag.contains("font-weight: bold") {
+        sgr.push_str("\u{1b}[1m");
+    }
+    if let Some(pos) = tag.find("color: #") {
+        let hex: String = tag[pos + 8..].chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        if let Some((r, g, b)) = parse_hex(&hex) {
+            sgr.push_str(&format!("\u{1b}[38;2;{};{};{}m", r, g, b));
+        }
+    }
+    sgr
+}
+
+fn parse_hex(hex: &str) -> Option<(u8, u8, u8)> {
+    let digits: Vec<u8> = hex.chars().filter_map(|c| c.to_digit(16).map(|d| d as u8)).collect();
+    match digits.len() {
+        3 => Some((digits[0] * 17, digits[1] * 17, digits[2] * 17)),
+        6 => Some((digits[0] * 16 + digits[1], digits[2] * 16 + digits[3], digits[4] * 16 + digits[5])),
+        _ => None,
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Converts ANSI SGR-coloured text back into output HTML.
+pub fn ansi_to_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut color: Option<String> = None;
+    let mut bold = false;
+    let mut open = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            let mut params = String::new();
+            let mut terminator = None;
+            for c in chars.by_ref() {
+                if c.is_ascii_digit() || c == ';' {
+                    params.push(c);
+                } else {
+                    terminator = Some(c);
+                    break;
+                }
+            }
+            if terminator != Some('m') {
+                continue;
+            }
+            apply_sgr(&params, &mut color, &mut bold);
+            if open {
+                out.push_str("</span>");
+                open = false;
+            }
+            let mut style = Vec::new();
+            if let Some(color) = &color {
+                style.push(format!("color: {}", color));
+            }
+            if bold {
+                style.push("font-weight: bold".to_string());
+            }
+            if !style.is_empty() {
+                out.push_str(&format!("<span style='{}'>", style.join("; ")));
+                open = true;
+            }
+        } else if c != '\r' {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                c => out.push(c),
+            }
+        }
+    }
+    if open {
+        out.push_str("</span>");
+    }
+    out
+}
+
+/// Applies one SGR parameter list to the current colour and weight.
+fn apply_sgr(params: &str, color: &mut Option<String>, bold: &mut bool) {
+    const BASIC: [&str; 8] = ["#000", "#f44", "#0f0", "#ffc600", "#0af", "#f0f", "#0ff", "#fff"];
+    let codes: Vec<u32> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 => { *color = None; *bold = false; }
+            1 => *bold = true,
+            22 => *bold = false,
+            39 => *color = None,
+            c @ 30..=37 => *color = Some(BASIC[(c - 30) as usize].to_string()),
+            c @ 90..=97 => *color = Some(BASIC[(c - 90) as usize].to_string()),
+            38 if codes.get(i + 1) == Some(&2) && i + 4 < codes.len() => {
+                *color = Some(format!("#{:02x}{:02x}{:02x}", codes[i + 2], codes[i + 3], codes[i + 4]));
+                i += 4;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+}
@@ -0,0 +1,180 @@
+//! `resume`: the whole content model as a single document, on screen or as
+//! a Markdown / plain-text / PDF download.
+
+use crate::browser::{self, escape_html};
+use crate::content::{SkillCategory, ACTIVITIES, CERTIFICATIONS, EDUCATION, PROFILE, PROJECTS, SKILLS};
+use crate::layout::{self, Span};
+
+struct Entry {
+    heading: String,
+    meta: Option<String>,
+    lines: Vec<String>,
+    bullets: Vec<String>,
+}
+
+struct Section {
+    title: &'static str,
+    entries: Vec<Entry>,
+}
+
+fn entry(heading: impl Into<String>) -> Entry {
+    Entry { heading: heading.into(), meta: None, lines: Vec::new(), bullets: Vec::new() }
+}
+
+fn sections() -> Vec<Section> {
+    let mut education = entry(format!("{}, {}", EDUCATION.degree, EDUCATION.field));
+    education.meta = Some(EDUCATION.graduation());
+    education.lines = vec![
+        format!("{}, {}", EDUCATION.institution, EDUCATION.location),
+        format!("CGPA: {}", EDUCATION.cgpa),
+        format!("Relevant coursework: {}", EDUCATION.coursework.join(", ")),
+    ];
+
+    let projects = PROJECTS.iter()
+        .map(|p| Entry {
+            heading: p.title.to_string(),
+            meta: Some(p.dates.to_string()),
+            lines: vec![p.summary.to_string(), format!("Tech: {}", p.tech.join(", "))],
+            bullets: p.highlights.iter().map(|h| h.to_string()).collect(),
+        })
+        .collect();
+
+    let skills = SkillCategory::ALL.iter()
+        .map(|&category| {
+            let mut e = entry(category.name());
+            let names: Vec<&str> = SKILLS.iter().filter(|s| s.category == category).map(|s| s.name).collect();
+            e.lines = vec![names.join(", ")];
+            e
+        })
+        .collect();
+
+    let certifications = CERTIFICATIONS.iter()
+        .map(|c| {
+            let mut e = entry(c.name);
+            e.meta = Some(c.status());
+            e.lines = vec![c.issuer.to_string()];
+            e
+        })
+        .collect();
+
+    let mut activities = entry("");
+    activities.bullets = ACTIVITIES.iter().map(|a| a.to_string()).collect();
+
+    let mut summary = entry("");
+    summary.lines = vec![
+        PROFILE.summary.to_string(),
+        format!("Focus: {} · Seeking: {}", PROFILE.focus, PROFILE.seeking),
+    ];
+
+    vec![
+        Section { title: "Summary", entries: vec![summary] },
+        Section { title: "Education", entries: vec![education] },
+        Section { title: "Projects", entries: projects },
+        Section { title: "Skills", entries: skills },
+        Section { title: "Certifications", entries: certifications },
+        Section { title: "Learning Activities", entries: vec![activities] },
+    ]
+}
+
+fn contact_line() -> String {
+    format!("{} · {} · {} · {}", PROFILE.location, PROFILE.email, PROFILE.linkedin, PROFILE.github)
+}
+
+fn render_html() -> String {
+    let mut out = format!(
+        "\n{}\n",
+        layout::banner(&[
+            Span::new(&PROFILE.name.to_uppercase(), "color: #fff; font-weight: bold"),
+            Span::new(PROFILE.role, "color: #0ff"),
+            Span::new(&contact_line(), "color: #888"),
+        ])
+    );
+    for section in sections() {
+        out.push_str(&format!("\n<span style='color: #f90; font-weight: bold'>{}</span>\n", section.title.to_uppercase()));
+        for e in section.entries {
+            if !e.heading.is_empty() {
+                let meta = e.meta.map(|m| format!(" <span style='color: #888'>({})</span>", m)).unwrap_or_default();
+                out.push_str(&format!("<span style='color: #0ff'>{}</span>{}\n", escape_html(&e.heading), meta));
+            }
+            for line in e.lines {
+                out.push_str(&format!("   {}\n", escape_html(&line)));
+            }
+            for bullet in e.bullets {
+                out.push_str(&format!("   • {}\n", escape_html(&bullet)));
+            }
+        }
+    }
+    out.push_str(&format!("
+<span style='color: #888'>💡 Download: resume --md · resume --txt · resume --pdf</span>
+{}
+", layout::footer()));
+    out
+}
+
+pub fn render_markdown() -> String {
+    let mut out = format!("# {}\n\n**{}**  \n{}\n", PROFILE.name, PROFILE.role, contact_line());
+    for section in sections() {
+        out.push_str(&format!("\n## {}\n\n", section.title));
+        for e in section.entries {
+            if !e.heading.is_empty() {
+                let meta = e.meta.map(|m| format!(" — *{}*", m)).unwrap_or_default();
+                out.push_str(&format!("### {}{}\n\n", e.heading, meta));
+            }
+            for line in e.lines {
+                out.push_str(&format!("{}\n\n", line));
+            }
+            for bullet in &e.bullets {
+                out.push_str(&format!("- {}\n", bullet));
+            }
+            if !e.bullets.is_empty() {
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+fn render_text() -> String {
+    let rule = "=".repeat(60);
+    let mut out = format!("{}\n{}\n{}\n{}\n", PROFILE.name.to_uppercase(), PROFILE.role, contact_line(), rule);
+    for section in sections() {
+        out.push_str(&format!("\n{}\n{}\n", section.title.to_uppercase(), "-".repeat(section.title.len())));
+        for e in section.entries {
+            if !e.heading.is_empty() {
+                let meta = e.meta.map(|m| format!(" ({})", m)).unwrap_or_default();
+                out.push_str(&format!("{}{}\n", e.heading, meta));
+            }
+            for line in e.lines {
+                out.push_str(&format!("  {}\n", line));
+            }
+            for bullet in e.bullets {
+                out.push_str(&format!("  - {}\n", bullet));
+            }
+        }
+    }
+    out
+}
+
+/// The bundled PDF, served next to the app (see index.html).
+pub const PDF: &str = "resume.pdf";
+
+fn downloaded(result: Result<(), wasm_bindgen::JsValue>, name: &str) -> Result<String, String> {
+    match result {
+        Ok(()) => Ok(format!("<span style='color: #0f0'>📄 Downloading {}</span>", name)),
+        Err(_) => Err("<span style='color: #f44'>resume: download failed</span>".to_string()),
+    }
+}
+
+pub fn resume_command(args: &[&str]) -> Result<String, String> {
+    match args {
+        [] => Ok(render_html()),
+        ["--md" | "--markdown"] => {
+            downloaded(browser::download("ashish-kumar-resume.md", "text/markdown", &render_markdown()), "ashish-kumar-resume.md")
+        }
+        ["--txt" | "--text"] => {
+            downloaded(browser::download("ashish-kumar-resume.txt", "text/plain", &render_text()), "ashish-kumar-resume.txt")
+        }
+        ["--pdf"] => downloaded(browser::download_url("ashish-kumar-resume.pdf", PDF), "ashish-kumar-resume.pdf"),
+        _ => Err("<span style='color: #888'>Usage: resume [--md | --txt | --pdf]</span>".to_string()),
+    }
+}
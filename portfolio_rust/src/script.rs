@@ -0,0 +1,814 @@
+//! `sh FILE`: a small Bourne-style interpreter for scripts in the virtual
+//! filesystem. It understands variables, `if`/`elif`/`else` on exit status,
+//! `for NAME in ...; do ...; done`, functions, `test`/`[`, `exit` and
+//! `return`; every other line is split into words and goes through
+//! `process_words`, with `> file` applied afterwards. A command that
+//! returns `Err` exits with status 1.
+
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+
+use crate::browser::escape_html;
+use crate::files::{self, Redirect};
+use crate::jobs;
+use crate::terminal::Terminal;
+use crate::vfs;
+
+/// Nested `sh` runs plus function calls, so runaway recursion stops with an
+/// error instead of overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Installed as `~/demo.sh`.
+pub const DEMO: &str = r#"# A tour of sh. Try: sh demo.sh Rust WebAssembly
+greet() {
+    echo "Hello, $1!"
+}
+
+greet "${USER}"
+
+if [ $# -eq 0 ]; then
+    echo "No arguments; try: sh demo.sh Rust WebAssembly"
+else
+    for topic in "$@"; do
+        echo "Topic: $topic"
+    done
+fi
+
+for file in about.txt resume.md notes.txt; do
+    if [ -f ~/$file ]; then
+        echo "~/$file exists"
+    else
+        echo "~/$file is missing"
+    fi
+done
+
+# The portfolio's own files are read-only, so this fails.
+if touch ~/projects/mine; then
+    echo "wrote into ~/projects?!"
+else
+    echo "~/projects is read-only (status $?)"
+fi
+"#;
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts one level of nesting; `false` once [`MAX_DEPTH`] is reached.
+fn enter() -> bool {
+    DEPTH.with(|d| {
+        if d.get() >= MAX_DEPTH {
+            return false;
+        }
+        d.set(d.get() + 1);
+        true
+    })
+}
+
+fn leave() {
+    DEPTH.with(|d| d.set(d.get().saturating_sub(1)));
+}
+
+/// Byte offset of the first character matching `pattern` that is outside
+/// quotes and not escaped, by the same rules as word expansion.
+pub fn find_unquoted(text: &str, pattern: impl Fn(char) -> bool) -> Option<usize> {
+    let mut quote = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') if matches!(chars.peek(), Some((_, '"' | '\\' | '$'))) => {
+                chars.next();
+            }
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '\\') => {
+                chars.next();
+            }
+            (None, c) if pattern(c) => return Some(i),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
+/// A valid variable name: a letter or `_`, then letters, digits and `_`.
+pub fn is_name(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// One command of the script: a line, or part of a line between `;`s.
+struct Line {
+    number: usize,
+    text: String,
+}
+
+/// Splits `source` into commands at newlines and unquoted `;`, dropping
+/// comments and joining `\`-continued lines.
+fn split(source: &str) -> VecDeque<Line> {
+    fn flush(lines: &mut VecDeque<Line>, text: &mut String, number: usize) {
+        let trimmed = text.trim();
+        if !trimmed.is_empty() {
+            lines.push_back(Line { number, text: trimmed.to_string() });
+        }
+        text.clear();
+    }
+
+    let mut lines = VecDeque::new();
+    let mut text = String::new();
+    let mut number = 1;
+    let mut quote = None;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' {
+            continue;
+        }
+        if let Some(q) = quote {
+            text.push(c);
+            if c == '\n' {
+                number += 1;
+            } else if c == q {
+                quote = None;
+            } else if c == '\\' && q == '"' {
+                text.extend(chars.next());
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' => {
+                quote = Some(c);
+                text.push(c);
+            }
+            '\\' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                number += 1;
+            }
+            '\\' => {
+                text.push(c);
+                text.extend(chars.next());
+            }
+            ';' => flush(&mut lines, &mut text, number),
+            '\n' => {
+                flush(&mut lines, &mut text, number);
+                number += 1;
+            }
+            '#' if text.is_empty() || text.ends_with(char::is_whitespace) => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            c => text.push(c),
+        }
+    }
+    flush(&mut lines, &mut text, number);
+    lines
+}
+
+/// First word of `text` and the rest, trimmed.
+fn first_word(text: &str) -> (&str, &str) {
+    match text.split_once(char::is_whitespace) {
+        Some((word, rest)) => (word, rest.trim()),
+        None => (text, ""),
+    }
+}
+
+enum Stmt {
+    Command { line: usize, text: String },
+    Assign { name: String, value: String },
+    If { cond: Vec<Stmt>, then: Vec<Stmt>, otherwise: Vec<Stmt> },
+    /// `words` is `None` for `for NAME; do`, which loops over the arguments.
+    For { name: String, words: Option<String>, body: Vec<Stmt> },
+    Function { name: String, body: Rc<Vec<Stmt>> },
+}
+
+/// A syntax error and the line it is on.
+type ParseError = (usize, String);
+
+struct Parser {
+    lines: VecDeque<Line>,
+    /// Line number of the last command taken, for "unexpected end of file".
+    last: usize,
+}
+
+impl Parser {
+    fn program(source: &str) -> Result<Vec<Stmt>, ParseError> {
+        let mut parser = Parser { lines: split(source), last: 1 };
+        parser.block(&[]).map(|(stmts, _)| stmts)
+    }
+
+    fn next(&mut self) -> Option<Line> {
+        let line = self.lines.pop_front()?;
+        self.last = line.number;
+        Some(line)
+    }
+
+    /// Puts the words after a keyword (`then echo hi`) back as a command.
+    fn push_back(&mut self, number: usize, rest: &str) {
+        if !rest.is_empty() {
+            self.lines.push_front(Line { number, text: rest.to_string() });
+        }
+    }
+
+    /// Statements up to one of the keywords in `until`, and which one
+    /// ended the block. An empty `until` reads to the end of the script.
+    fn block(&mut self, until: &[&'static str]) -> Result<(Vec<Stmt>, &'static str), ParseError> {
+        let mut stmts = Vec::new();
+        while let Some(line) = self.next() {
+            let (word, rest) = first_word(&line.text);
+            if let Some(&end) = until.iter().find(|&&end| end == word) {
+                self.push_back(line.number, rest);
+                return Ok((stmts, end));
+            }
+            match word {
+                "if" => {
+                    self.push_back(line.number, rest);
+                    stmts.push(self.parse_if(line.number)?);
+                }
+                "for" => stmts.push(self.parse_for(line.number, rest)?),
+                "then" | "do" | "done" | "fi" | "else" | "elif" | "}" => {
+                    return Err((line.number, format!("syntax error near unexpected '{}'", word)));
+                }
+                _ => {
+                    if let Some(stmt) = self.parse_function(&line)? {
+                        stmts.push(stmt);
+                    } else if let Some((name, value)) = line.text.split_once('=').filter(|(name, _)| is_name(name)) {
+                        stmts.push(Stmt::Assign { name: name.to_string(), value: value.to_string() });
+                    } else {
+                        stmts.push(Stmt::Command { line: line.number, text: line.text });
+                    }
+                }
+            }
+        }
+        // The keyword that closes the construct: `fi`, not `elif`.
+        match until.last() {
+            None => Ok((stmts, "")),
+            Some(end) => Err((self.last, format!("syntax error: unexpected end of file (expecting '{}')", end))),
+        }
+    }
+
+    /// After `if`: the condition, the branch, and any `elif`/`else`.
+    fn parse_if(&mut self, number: usize) -> Result<Stmt, ParseError> {
+        let (cond, _) = self.block(&["then"])?;
+        if cond.is_empty() {
+            return Err((number, "syntax error: expected a command after 'if'".to_string()));
+        }
+        let (then, end) = self.block(&["elif", "else", "fi"])?;
+        let otherwise = match end {
+            "elif" => vec![self.parse_if(self.last)?],
+            "else" => self.block(&["fi"])?.0,
+            _ => Vec::new(),
+        };
+        Ok(Stmt::If { cond, then, otherwise })
+    }
+
+    /// `for NAME [in WORDS...]` followed by `do ... done`.
+    fn parse_for(&mut self, number: usize, rest: &str) -> Result<Stmt, ParseError> {
+        let (name, rest) = first_word(rest);
+        if !is_name(name) {
+            return Err((number, format!("syntax error: '{}' is not a valid loop variable", name)));
+        }
+        let words = match first_word(rest) {
+            ("", _) => None,
+            ("in", words) => Some(words.to_string()),
+            _ => return Err((number, "syntax error: expected 'in' after the loop variable".to_string())),
+        };
+        self.expect(number, "do")?;
+        let (body, _) = self.block(&["done"])?;
+        Ok(Stmt::For { name: name.to_string(), words, body })
+    }
+
+    /// `NAME() {`, `NAME () {` or `function NAME {`, with the brace on the
+    /// same line or the next. `None` if `line` is not a function header.
+    fn parse_function(&mut self, line: &Line) -> Result<Option<Stmt>, ParseError> {
+        let (keyword, text) = match line.text.strip_prefix("function ") {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, line.text.as_str()),
+        };
+        let end = text.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_')).unwrap_or(text.len());
+        let (name, after) = (&text[..end], text[end..].trim_start());
+        let after = match after.strip_prefix("()") {
+            Some(after) => after.trim_start(),
+            None if keyword => after,
+            None => return Ok(None),
+        };
+        if !is_name(name) {
+            return if keyword { Err((line.number, "syntax error: expected a function name".to_string())) } else { Ok(None) };
+        }
+        match after.strip_prefix('{') {
+            Some(rest) => self.push_back(line.number, rest.trim()),
+            None if after.is_empty() => self.expect(line.number, "{")?,
+            None => return Err((line.number, "syntax error: expected '{' after the function name".to_string())),
+        }
+        let (body, _) = self.block(&["}"])?;
+        Ok(Some(Stmt::Function { name: name.to_string(), body: Rc::new(body) }))
+    }
+
+    /// Takes `keyword` from the start of the next command.
+    fn expect(&mut self, number: usize, keyword: &str) -> Result<(), ParseError> {
+        match self.next() {
+            Some(line) if first_word(&line.text).0 == keyword => {
+                let rest = first_word(&line.text).1.to_string();
+                self.push_back(line.number, &rest);
+                Ok(())
+            }
+            Some(line) => Err((line.number, format!("syntax error: expected '{}'", keyword))),
+            None => Err((number, format!("syntax error: unexpected end of file (expecting '{}')", keyword))),
+        }
+    }
+}
+
+/// What running a statement asks of the statements around it.
+#[derive(PartialEq)]
+enum Flow {
+    Next,
+    Return,
+    Exit,
+}
+
+/// What a script needs from the terminal it runs in.
+trait Host {
+    fn var(&self, name: &str) -> Option<String>;
+    /// Runs a command that is not a shell builtin, writing its output to
+    /// `redirect` if there is one; what to print, as `Err` if it failed.
+    fn command(&self, words: &[String], redirect: Option<&Redirect>) -> Result<String, String>;
+}
+
+impl Host for Terminal {
+    fn var(&self, name: &str) -> Option<String> {
+        Terminal::var(self, name)
+    }
+
+    fn command(&self, words: &[String], redirect: Option<&Redirect>) -> Result<String, String> {
+        let output = crate::process_words(self, words);
+        match redirect {
+            Some(redirect) => files::redirect(self, redirect, output),
+            None => output,
+        }
+    }
+}
+
+struct Shell<'a> {
+    host: &'a dyn Host,
+    /// The script as it was named, `$0`.
+    script: String,
+    args: Vec<String>,
+    /// Variables set in the script; anything else falls back to the
+    /// terminal's environment.
+    vars: HashMap<String, String>,
+    functions: HashMap<String, Rc<Vec<Stmt>>>,
+    /// Exit status of the last command, `$?`.
+    status: i32,
+    output: Vec<String>,
+}
+
+pub fn sh_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    let Some((file, args)) = args.split_first() else {
+        return Err("<span style='color: #888'>Usage: sh FILE [ARG...]   (try: sh demo.sh)</span>".to_string());
+    };
+    let path = vfs::resolve(&term.var("PWD").unwrap_or_default(), file);
+    match vfs::read(&path) {
+        Ok(source) => run(term, file, &source, args.iter().map(|arg| arg.to_string()).collect()),
+        Err(err) => Err(vfs::error("sh", &path, err)),
+    }
+}
+
+/// Parses and runs `source` as the script `name`; its output, as `Err` if
+/// it exits with a non-zero status.
+fn run(host: &dyn Host, name: &str, source: &str, args: Vec<String>) -> Result<String, String> {
+    let program = match Parser::program(source) {
+        Ok(program) => program,
+        Err((line, message)) => {
+            return Err(format!("<span style='color: #f44'>sh: {}: line {}: {}</span>", escape_html(name), line, escape_html(&message)));
+        }
+    };
+    let mut shell = Shell::new(host, name, args);
+    if enter() {
+        shell.exec(&program);
+        leave();
+    } else {
+        shell.error(0, "maximum nesting depth exceeded");
+        shell.status = 1;
+    }
+    let output = shell.output.join("\n");
+    if shell.status == 0 { Ok(output) } else { Err(output) }
+}
+
+/// Expands a typed command line's `text` as a script line would be, with
+/// the terminal's environment as the only variables.
+pub fn expand(term: &Terminal, text: &str) -> Vec<String> {
+    Shell::new(term, "sh", Vec::new()).expand(text)
+}
+
+impl<'a> Shell<'a> {
+    fn new(host: &'a dyn Host, script: &str, args: Vec<String>) -> Shell<'a> {
+        Shell {
+            host,
+            script: script.to_string(),
+            args,
+            vars: HashMap::new(),
+            functions: HashMap::new(),
+            status: 0,
+            output: Vec::new(),
+        }
+    }
+
+    fn error(&mut self, line: usize, message: &str) {
+        let at = if line == 0 { String::new() } else { format!("line {}: ", line) };
+        self.output.push(format!(
+            "<span style='color: #f44'>sh: {}: {}{}</span>",
+            escape_html(&self.script), at, escape_html(message)
+        ));
+    }
+
+    fn exec(&mut self, stmts: &[Stmt]) -> Flow {
+        for stmt in stmts {
+            let flow = match stmt {
+                Stmt::Command { line, text } => self.run(*line, text),
+                Stmt::Assign { name, value } => {
+                    let value = self.expand(value).join(" ");
+                    self.vars.insert(name.clone(), value);
+                    self.status = 0;
+                    Flow::Next
+                }
+                Stmt::If { cond, then, otherwise } => match self.exec(cond) {
+                    Flow::Next if self.status == 0 => self.exec(then),
+                    Flow::Next if otherwise.is_empty() => {
+                        self.status = 0;
+                        Flow::Next
+                    }
+                    Flow::Next => self.exec(otherwise),
+                    flow => flow,
+                },
+                Stmt::For { name, words, body } => {
+                    let values = match words {
+                        Some(words) => self.expand(words),
+                        None => self.args.clone(),
+                    };
+                    self.status = 0;
+                    let mut flow = Flow::Next;
+                    for value in values {
+                        self.vars.insert(name.clone(), value);
+                        flow = self.exec(body);
+                        if flow != Flow::Next {
+                            break;
+                        }
+                    }
+                    flow
+                }
+                Stmt::Function { name, body } => {
+                    self.functions.insert(name.clone(), Rc::clone(body));
+                    self.status = 0;
+                    Flow::Next
+                }
+            };
+            if flow != Flow::Next {
+                return flow;
+            }
+        }
+        Flow::Next
+    }
+
+    fn run(&mut self, line: usize, text: &str) -> Flow {
+        let (text, redirect) = match files::split_redirect(text) {
+            Some(Ok(redirect)) => (redirect.command, Some(redirect)),
+            Some(Err(error)) => {
+                self.output.push(error);
+                self.status = 2;
+                return Flow::Next;
+            }
+            None => (text, None),
+        };
+        let words = self.expand(text);
+        let Some(command) = words.first() else { return Flow::Next };
+        match command.as_str() {
+            "true" | ":" => self.status = 0,
+            "false" => self.status = 1,
+            "test" | "[" => self.status = self.test(line, &words),
+            "exit" | "return" => {
+                if let Some(code) = words.get(1) {
+                    self.status = code.parse().unwrap_or(2);
+                }
+                return if command == "exit" { Flow::Exit } else { Flow::Return };
+            }
+            name if self.functions.contains_key(name) => {
+                let body = Rc::clone(&self.functions[name]);
+                return self.call(line, &body, words[1..].to_vec());
+            }
+            name if jobs::ASYNC_COMMANDS.contains(&name.to_lowercase().as_str()) => {
+                self.error(line, &format!("{}: not available in scripts", name));
+                self.status = 1;
+            }
+            _ => {
+                let target = redirect.as_ref().map(|redirect| self.expand(redirect.target).join(" "));
+                let redirect = redirect.zip(target.as_deref()).map(|(redirect, target)| Redirect { target, ..redirect });
+                let result = self.host.command(&words, redirect.as_ref());
+                self.status = i32::from(result.is_err());
+                let output = result.unwrap_or_else(|e| e);
+                if !output.is_empty() {
+                    self.output.push(output);
+                }
+            }
+        }
+        Flow::Next
+    }
+
+    /// Runs a function with `args` as `$1`, `$2`, ...
+    fn call(&mut self, line: usize, body: &[Stmt], args: Vec<String>) -> Flow {
+        if !enter() {
+            self.error(line, "maximum nesting depth exceeded");
+            self.status = 1;
+            return Flow::Exit;
+        }
+        let saved = std::mem::replace(&mut self.args, args);
+        let flow = self.exec(body);
+        self.args = saved;
+        leave();
+        if flow == Flow::Exit { Flow::Exit } else { Flow::Next }
+    }
+
+    /// `test EXPR` / `[ EXPR ]`: 0 if true, 1 if false, 2 if malformed.
+    fn test(&mut self, line: usize, words: &[String]) -> i32 {
+        let mut args: Vec<&str> = words[1..].iter().map(String::as_str).collect();
+        if words[0] == "[" && args.pop() != Some("]") {
+            self.error(line, "[: missing ']'");
+            return 2;
+        }
+        let negate = args.first() == Some(&"!");
+        if negate {
+            args.remove(0);
+        }
+        let cwd = self.host.var("PWD").unwrap_or_default();
+        let path = |p: &str| vfs::resolve(&cwd, p);
+        let number = |n: &str| n.parse::<i64>().ok();
+        let result = match args.as_slice() {
+            [] => Some(false),
+            [s] => Some(!s.is_empty()),
+            ["-z", s] => Some(s.is_empty()),
+            ["-n", s] => Some(!s.is_empty()),
+            ["-e", p] => Some(vfs::exists(&path(p))),
+            ["-f", p] => Some(vfs::exists(&path(p)) && !vfs::is_dir(&path(p))),
+            ["-d", p] => Some(vfs::is_dir(&path(p))),
+            [a, "=" | "==", b] => Some(a == b),
+            [a, "!=", b] => Some(a != b),
+            [a, op, b] => number(a).zip(number(b)).and_then(|(a, b)| match *op {
+                "-eq" => Some(a == b),
+                "-ne" => Some(a != b),
+                "-lt" => Some(a < b),
+                "-le" => Some(a <= b),
+                "-gt" => Some(a > b),
+                "-ge" => Some(a >= b),
+                _ => None,
+            }),
+            _ => None,
+        };
+        match result {
+            Some(result) => i32::from(result == negate),
+            None => {
+                self.error(line, &format!("{}: bad expression: {}", words[0], args.join(" ")));
+                2
+            }
+        }
+    }
+
+    /// Values of a variable: several for `$@` and `$*`, one otherwise.
+    fn lookup(&self, name: &str) -> Vec<String> {
+        let value = match name {
+            "@" => return self.args.clone(),
+            "*" => self.args.join(" "),
+            "?" => self.status.to_string(),
+            "#" => self.args.len().to_string(),
+            "0" => self.script.clone(),
+            n if n.chars().all(|c| c.is_ascii_digit()) => {
+                n.parse::<usize>().ok().and_then(|i| self.args.get(i.checked_sub(1)?)).cloned().unwrap_or_default()
+            }
+            name => self.vars.get(name).cloned().or_else(|| self.host.var(name)).unwrap_or_default(),
+        };
+        vec![value]
+    }
+
+    /// The variable after a `$`: `${NAME}`, `$NAME`, `$1`, `$?`, `$#`, `$@`.
+    /// `None` if no variable follows.
+    fn variable(&self, chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<Vec<String>> {
+        let name = match *chars.peek()? {
+            '{' => {
+                chars.next();
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                name
+            }
+            c @ ('?' | '#' | '@' | '*') | c @ '0'..='9' => {
+                chars.next();
+                c.to_string()
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut name = String::new();
+                while let Some(c) = chars.next_if(|&c| c.is_ascii_alphanumeric() || c == '_') {
+                    name.push(c);
+                }
+                name
+            }
+            _ => return None,
+        };
+        Some(self.lookup(&name))
+    }
+
+    /// Expands variables and splits `text` into words, honouring quotes:
+    /// nothing is expanded inside `'...'`, and `"..."` stays one word (except
+    /// `"$@"`, which is one word per argument).
+    fn expand(&self, text: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut word = String::new();
+        // Whether there is a word in progress, even an empty quoted one.
+        let mut started = false;
+        let mut quote = None;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (quote, c) {
+                (Some('\''), '\'') | (Some('"'), '"') => quote = None,
+                (Some('\''), c) => word.push(c),
+                (None, '\'' | '"') => {
+                    quote = Some(c);
+                    started = true;
+                }
+                (Some(_), '\\') if matches!(chars.peek(), Some('"' | '\\' | '$')) => word.extend(chars.next()),
+                (None, '\\') => {
+                    word.extend(chars.next());
+                    started = true;
+                }
+                (_, '$') => match self.variable(&mut chars) {
+                    None => {
+                        word.push('$');
+                        started = true;
+                    }
+                    Some(values) if quote.is_some() => {
+                        for (i, value) in values.iter().enumerate() {
+                            if i > 0 {
+                                words.push(std::mem::take(&mut word));
+                            }
+                            word.push_str(value);
+                            started = true;
+                        }
+                    }
+                    // Unquoted: the value is split into words at whitespace.
+                    Some(values) => {
+                        let value = values.join(" ");
+                        if value.starts_with(char::is_whitespace) && started {
+                            words.push(std::mem::take(&mut word));
+                            started = false;
+                        }
+                        for (i, field) in value.split_whitespace().enumerate() {
+                            if i > 0 {
+                                words.push(std::mem::take(&mut word));
+                            }
+                            word.push_str(field);
+                            started = true;
+                        }
+                        if value.ends_with(char::is_whitespace) && started {
+                            words.push(std::mem::take(&mut word));
+                            started = false;
+                        }
+                    }
+                },
+                (None, c) if c.is_whitespace() => {
+                    if started {
+                        words.push(std::mem::take(&mut word));
+                        started = false;
+                    }
+                }
+                (_, c) => {
+                    word.push(c);
+                    started = true;
+                }
+            }
+        }
+        if started {
+            words.push(word);
+        }
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// Records the commands a script runs; `fail` reports an error.
+    #[derive(Default)]
+    struct Recorder {
+        commands: RefCell<Vec<Vec<String>>>,
+    }
+
+    impl Host for Recorder {
+        fn var(&self, name: &str) -> Option<String> {
+            (name == "USER").then(|| "guest".to_string())
+        }
+
+        fn command(&self, words: &[String], _: Option<&Redirect>) -> Result<String, String> {
+            self.commands.borrow_mut().push(words.to_vec());
+            if words[0] == "fail" {
+                Err("<span style='color: #f44'>fail</span>".to_string())
+            } else {
+                Ok(String::new())
+            }
+        }
+    }
+
+    /// The commands `source` runs with `args`, each as its words joined by `|`.
+    fn commands(source: &str, args: &[&str]) -> Vec<String> {
+        let host = Recorder::default();
+        let _ = run(&host, "test.sh", source, args.iter().map(|a| a.to_string()).collect());
+        host.commands.into_inner().iter().map(|words| words.join("|")).collect()
+    }
+
+    fn parse_error(source: &str) -> (usize, String) {
+        match Parser::program(source) {
+            Ok(_) => panic!("{:?} parsed", source),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn parse_errors_name_the_line() {
+        assert_eq!(parse_error("echo hi\nfi"), (2, "syntax error near unexpected 'fi'".to_string()));
+        assert_eq!(parse_error("if true; then\n  echo hi\n"), (2, "syntax error: unexpected end of file (expecting 'fi')".to_string()));
+        assert_eq!(parse_error("if true; then echo; elif false; then echo"), (1, "syntax error: unexpected end of file (expecting 'fi')".to_string()));
+        assert_eq!(parse_error("for 1x in a; do echo; done").1, "syntax error: '1x' is not a valid loop variable");
+        assert_eq!(parse_error("function { echo; }").1, "syntax error: expected a function name");
+    }
+
+    #[test]
+    fn quoted_at_keeps_each_argument_whole() {
+        let source = r#"for a in "$@"; do echo "$a"; done
+for a in $@; do echo $a; done
+echo "[$*]" "$#""#;
+        assert_eq!(
+            commands(source, &["one two", "three"]),
+            ["echo|one two", "echo|three", "echo|one", "echo|two", "echo|three", "echo|[one two three]|2"]
+        );
+        assert_eq!(commands(r#"echo '$USER' "$USER" \$USER"#, &[]), ["echo|$USER|guest|$USER"]);
+    }
+
+    #[test]
+    fn nested_if_and_elif() {
+        let source = "
+check() {
+    if [ $1 -lt 10 ]; then
+        if [ $1 -lt 5 ]; then echo small; else echo medium; fi
+    elif [ $1 -lt 100 ]; then
+        echo large
+    else
+        echo huge
+    fi
+}
+for n in 1 7 42 500; do check $n; done
+if fail; then echo no; elif true; then echo status $?; fi";
+        assert_eq!(commands(source, &[]), ["echo|small", "echo|medium", "echo|large", "echo|huge", "fail", "echo|status|0"]);
+    }
+
+    #[test]
+    fn return_leaves_the_function_and_exit_the_script() {
+        let source = "
+f() {
+    echo in f
+    return 3
+    echo unreachable
+}
+f
+echo after f $?
+for i in 1 2 3; do
+    if [ $i -eq 2 ]; then exit 5; fi
+    echo loop $i
+done
+echo unreachable";
+        assert_eq!(commands(source, &[]), ["echo|in|f", "echo|after|f|3", "echo|loop|1"]);
+    }
+
+    #[test]
+    fn async_commands_are_refused() {
+        let host = Recorder::default();
+        let output = run(&host, "wait.sh", "sleep 1\necho status $?", Vec::new()).unwrap();
+        assert!(output.contains("sh: wait.sh: line 1: sleep: not available in scripts"), "{}", output);
+        assert_eq!(host.commands.into_inner(), [["echo", "status", "1"]]);
+    }
+
+    #[test]
+    fn redirect_needs_a_command() {
+        let host = Recorder::default();
+        let output = run(&host, "redirect.sh", "> out.txt\necho status $?", Vec::new()).unwrap();
+        assert!(output.contains("syntax error: expected a command before '&gt;'"), "{}", output);
+        assert_eq!(host.commands.into_inner(), [["echo", "status", "2"]]);
+    }
+
+    #[test]
+    fn runaway_recursion_stops_with_an_error() {
+        let host = Recorder::default();
+        let output = run(&host, "loop.sh", "f() {\n    f\n}\nf\necho done", Vec::new()).unwrap_err();
+        assert!(output.contains("sh: loop.sh: line 2: maximum nesting depth exceeded"), "{}", output);
+        // Exceeding the limit ends the script, and the depth unwinds.
+        assert!(host.commands.borrow().is_empty());
+        assert!(enter());
+        leave();
+    }
+}
@@ -0,0 +1,234 @@
+//! `search`: full-text search over everything the portfolio knows about
+//! (profile, education, projects, skills, certifications, GitHub repos),
+//! backed by an inverted index built once per page load.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::browser::escape_html;
+use crate::content::{SkillCategory, ACTIVITIES, CERTIFICATIONS, EDUCATION, PROFILE, PROJECTS, SKILLS};
+use crate::github::REPOS;
+use crate::output::{self, highlight, words, Match};
+
+/// Results shown at most.
+const MAX_RESULTS: usize = 10;
+
+/// Title matches count this much more than body matches.
+const TITLE_WEIGHT: f64 = 3.0;
+
+/// A searchable unit and the command that shows it in full.
+struct Document {
+    section: &'static str,
+    title: String,
+    command: String,
+    lines: Vec<String>,
+}
+
+/// One occurrence of a term: the document and the line in it (`None` for
+/// the title).
+#[derive(Clone, Copy)]
+struct Posting {
+    doc: usize,
+    line: Option<usize>,
+}
+
+struct Index {
+    docs: Vec<Document>,
+    /// Lowercased term → occurrences; ordered so prefixes are a range scan.
+    postings: BTreeMap<String, Vec<Posting>>,
+}
+
+struct Hit {
+    doc: usize,
+    score: f64,
+    /// The line with the most query terms, for the snippet.
+    line: Option<usize>,
+}
+
+thread_local! {
+    static INDEX: Index = Index::build();
+}
+
+/// Builds the index ahead of the first search.
+pub fn init() {
+    INDEX.with(|_| ());
+}
+
+fn documents() -> Vec<Document> {
+    let mut docs = vec![
+        Document {
+            section: "About",
+            title: PROFILE.name.to_string(),
+            command: "whoami".to_string(),
+            lines: vec![
+                PROFILE.role.to_string(),
+                PROFILE.summary.to_string(),
+                format!("Focus: {}", PROFILE.focus),
+                format!("Seeking: {}", PROFILE.seeking),
+                PROFILE.location.to_string(),
+            ],
+        },
+        Document {
+            section: "Education",
+            title: EDUCATION.degree.to_string(),
+            command: "education".to_string(),
+            lines: [EDUCATION.field, EDUCATION.institution]
+                .into_iter()
+                .chain(EDUCATION.coursework.iter().copied())
+                .map(str::to_string)
+                .collect(),
+        },
+    ];
+
+    docs.extend(PROJECTS.iter().map(|p| Document {
+        section: "Projects",
+        title: p.title.to_string(),
+        command: format!("cat projects/{}", p.id),
+        lines: std::iter::once(p.summary.to_string())
+            .chain(p.highlights.iter().map(|h| h.to_string()))
+            .chain([format!("Tech: {}", p.tech.join(", ")), format!("Tags: {}", p.tags.join(", "))])
+            .collect(),
+    }));
+
+    docs.extend(SkillCategory::ALL.iter().map(|&category| Document {
+        section: "Skills",
+        title: category.name().to_string(),
+        command: format!("skills --category {}", category.key()),
+        lines: SKILLS.iter().filter(|s| s.category == category).map(|s| s.name.to_string()).collect(),
+    }));
+
+    docs.extend(CERTIFICATIONS.iter().map(|c| Document {
+        section: "Certifications",
+        title: c.name.to_string(),
+        command: "certifications".to_string(),
+        lines: vec![format!("{} ({})", c.issuer, c.status())],
+    }));
+    docs.push(Document {
+        section: "Certifications",
+        title: "Learning Activities".to_string(),
+        command: "certifications".to_string(),
+        lines: ACTIVITIES.iter().map(|a| a.to_string()).collect(),
+    });
+
+    docs.extend(REPOS.iter().map(|r| Document {
+        section: "GitHub",
+        title: r.name.to_string(),
+        command: format!("gh repo {}", r.name),
+        lines: [r.description, r.language]
+            .into_iter()
+            .chain(r.readme.lines().map(str::trim).filter(|l| !l.is_empty()))
+            .map(str::to_string)
+            .collect(),
+    }));
+    docs
+}
+
+impl Index {
+    fn build() -> Index {
+        let docs = documents();
+        let mut postings: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+        for (doc, d) in docs.iter().enumerate() {
+            let fields = std::iter::once((None, &d.title)).chain(d.lines.iter().enumerate().map(|(i, l)| (Some(i), l)));
+            for (line, text) in fields {
+                for (start, end) in words(text) {
+                    postings.entry(text[start..end].to_lowercase()).or_default().push(Posting { doc, line });
+                }
+            }
+        }
+        Index { docs, postings }
+    }
+
+    /// Ranks documents containing every term (as a word prefix) by a
+    /// TF-IDF style score, title matches weighted up.
+    fn search(&self, terms: &[String]) -> Vec<Hit> {
+        let total = self.docs.len() as f64;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        let mut matched: HashMap<usize, usize> = HashMap::new();
+        let mut line_hits: HashMap<(usize, usize), usize> = HashMap::new();
+
+        for term in terms {
+            let occurrences: Vec<(bool, Posting)> = self.postings
+                .range(term.clone()..)
+                .take_while(|(word, _)| word.starts_with(term.as_str()))
+                .flat_map(|(word, list)| list.iter().map(move |p| (word == term, *p)))
+                .collect();
+            let docs: HashSet<usize> = occurrences.iter().map(|(_, p)| p.doc).collect();
+            if docs.is_empty() {
+                return Vec::new();
+            }
+            let idf = (1.0 + total / docs.len() as f64).ln();
+
+            for (exact, posting) in &occurrences {
+                let weight = if posting.line.is_none() { TITLE_WEIGHT } else { 1.0 };
+                let weight = if *exact { weight } else { weight / 2.0 };
+                *scores.entry(posting.doc).or_default() += weight * idf;
+                if let Some(line) = posting.line {
+                    *line_hits.entry((posting.doc, line)).or_default() += 1;
+                }
+            }
+            for doc in docs {
+                *matched.entry(doc).or_default() += 1;
+            }
+        }
+
+        let mut hits: Vec<Hit> = scores.into_iter()
+            .filter(|(doc, _)| matched.get(doc) == Some(&terms.len()))
+            .map(|(doc, score)| {
+                let line = line_hits.iter()
+                    .filter(|((d, _), _)| *d == doc)
+                    .max_by_key(|((_, line), count)| (**count, std::cmp::Reverse(*line)))
+                    .map(|((_, line), _)| *line);
+                Hit { doc, score, line }
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.doc.cmp(&b.doc)));
+        hits
+    }
+}
+
+pub fn search_command(args: &[&str]) -> Result<String, String> {
+    let query = args.join(" ");
+    let terms: Vec<String> = {
+        let mut terms: Vec<String> = words(&query).into_iter().map(|(s, e)| query[s..e].to_lowercase()).collect();
+        terms.sort_unstable();
+        terms.dedup();
+        terms
+    };
+    if terms.is_empty() {
+        return Err("<span style='color: #888'>Usage: search QUERY</span>\n<span style='color: #888'>Example: search wireshark</span>".to_string());
+    }
+
+    Ok(INDEX.with(|index| {
+        let hits = index.search(&terms);
+        if hits.is_empty() {
+            return format!(
+                "<span style='color: #888'>🔎 Nothing matches '{}'. Try fewer or shorter words.</span>",
+                escape_html(&query)
+            );
+        }
+
+        let rows: Vec<String> = hits.iter()
+            .take(MAX_RESULTS)
+            .enumerate()
+            .map(|(i, hit)| {
+                let doc = &index.docs[hit.doc];
+                let snippet = hit.line.or(if doc.lines.is_empty() { None } else { Some(0) })
+                    .map(|line| format!("\n    <span style='color: #ccc'>{}</span>", highlight(&doc.lines[line], Match::WordPrefix(&terms))))
+                    .unwrap_or_default();
+                format!(
+                    "<span style='color: #888'>{:>2}.</span> <span style='color: #0ff'>{}</span>  <span style='color: #888'>[{}]</span>{}\n    <span style='color: #888'>→</span> {}",
+                    i + 1, highlight(&doc.title, Match::WordPrefix(&terms)), doc.section, snippet, output::command_link(&doc.command)
+                )
+            })
+            .collect();
+        let more = if hits.len() > MAX_RESULTS {
+            format!("\n<span style='color: #888'>... and {} more; add words to narrow it down.</span>", hits.len() - MAX_RESULTS)
+        } else {
+            String::new()
+        };
+
+        format!(
+            "<span style='color: #ffc600'>🔎 {} result{} for '{}'</span>\n\n{}{}\n\n<span style='color: #888'>💡 Click a command (or Shift+Tab to it and press Enter) to jump there.</span>",
+            hits.len(), if hits.len() == 1 { "" } else { "s" }, escape_html(&query), rows.join("\n\n"), more
+        )
+    }))
+}
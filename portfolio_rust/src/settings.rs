@@ -0,0 +1,110 @@
+//! Per-terminal preferences changed with `set`.
+
+use crate::browser;
+use crate::terminal::Terminal;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Typing {
+    Off,
+    Chars,
+    Lines,
+}
+
+impl Typing {
+    fn name(self) -> &'static str {
+        match self {
+            Typing::Off => "off",
+            Typing::Chars => "on (characters)",
+            Typing::Lines => "on (lines)",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Theme {
+    Cobalt,
+    Matrix,
+    Solarized,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Cobalt, Theme::Matrix, Theme::Solarized];
+
+    /// Name for `set theme` and the pane's `data-theme` attribute.
+    pub fn key(self) -> &'static str {
+        match self {
+            Theme::Cobalt => "cobalt",
+            Theme::Matrix => "matrix",
+            Theme::Solarized => "solarized",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Cobalt => "Dark (Cobalt2-inspired)",
+            Theme::Matrix => "Matrix (green glow)",
+            Theme::Solarized => "Solarized Dark",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Settings {
+    pub typing: Typing,
+    /// Streaming speed in characters per second.
+    pub typing_speed: u32,
+    pub theme: Theme,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings { typing: Typing::Off, typing_speed: 600, theme: Theme::Cobalt }
+    }
+}
+
+const USAGE: &str = "<span style='color: #888'>Usage: set typing on|off|line|speed N | set theme NAME</span>";
+
+pub fn set_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    match args {
+        [] => {
+            let settings = term.settings();
+            Ok(format!(
+                "<span style='color: #0ff'>typing</span>         {}\n<span style='color: #0ff'>typing speed</span>   {} chars/s\n<span style='color: #0ff'>theme</span>          {} ({})",
+                settings.typing.name(), settings.typing_speed, settings.theme.key(), settings.theme.name()
+            ))
+        }
+        ["typing", "on" | "char" | "chars"] => {
+            term.update_settings(|s| s.typing = Typing::Chars);
+            Ok("Typing animation on".to_string())
+        }
+        ["typing", "line" | "lines"] => {
+            term.update_settings(|s| s.typing = Typing::Lines);
+            Ok("Typing animation on (line by line)".to_string())
+        }
+        ["typing", "off"] => {
+            term.update_settings(|s| s.typing = Typing::Off);
+            Ok("Typing animation off".to_string())
+        }
+        ["typing", "speed", n] => match n.parse::<u32>() {
+            Ok(speed) if speed > 0 => {
+                term.update_settings(|s| s.typing_speed = speed);
+                Ok(format!("Typing speed set to {} chars/s", speed))
+            }
+            _ => Err(format!("<span style='color: #f44'>set: invalid speed '{}'</span>\n{}", browser::escape_html(n), USAGE)),
+        },
+        ["theme", name] => match Theme::ALL.into_iter().find(|t| t.key() == name.to_lowercase()) {
+            Some(theme) => {
+                term.update_settings(|s| s.theme = theme);
+                Ok(format!("Theme set to {}", theme.name()))
+            }
+            None => {
+                let names: Vec<&str> = Theme::ALL.iter().map(|t| t.key()).collect();
+                Err(format!(
+                    "<span style='color: #f44'>set: unknown theme '{}'</span>\n<span style='color: #888'>Themes: {}</span>",
+                    browser::escape_html(name), names.join(", ")
+                ))
+            }
+        },
+        _ => Err(format!("<span style='color: #f44'>set: unknown setting '{}'</span>\n{}", browser::escape_html(&args.join(" ")), USAGE)),
+    }
+}
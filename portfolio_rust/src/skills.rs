@@ -0,0 +1,178 @@
+//! `skills`: the skills model rendered as bar charts, with filtering and
+//! sorting (`--category`, `--min`, `--sort`).
+
+use std::cmp::Ordering;
+
+use crate::browser::escape_html;
+use crate::content::{Skill, SkillCategory, MAX_LEVEL, SKILLS};
+use crate::layout::{self, Align, Span, Table};
+
+/// Bar cells per proficiency level.
+const CELLS_PER_LEVEL: usize = 2;
+
+const USAGE: &str = "<span style='color: #888'>Usage: skills [--category NAME] [--min LEVEL] [--sort level|name]</span>";
+
+#[derive(Clone, Copy)]
+enum Sort {
+    Level,
+    Name,
+}
+
+#[derive(Default)]
+struct Query {
+    category: Option<SkillCategory>,
+    min: u8,
+    sort: Option<Sort>,
+}
+
+pub fn skills_command(args: &[&str]) -> Result<String, String> {
+    match parse(args) {
+        Ok(query) => Ok(render(&query)),
+        Err(err) => Err(format!("<span style='color: #f44'>skills: {}</span>\n{}", err, USAGE)),
+    }
+}
+
+fn parse(args: &[&str]) -> Result<Query, String> {
+    let mut query = Query::default();
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        let mut value = |flag: &str| args.next().copied().ok_or_else(|| format!("{} needs a value", flag));
+        match arg {
+            "--category" | "-c" => {
+                let name = value(arg)?;
+                query.category = Some(find_category(name).ok_or_else(|| {
+                    let keys: Vec<&str> = SkillCategory::ALL.iter().map(|c| c.key()).collect();
+                    format!("unknown category '{}' (try {})", escape_html(name), keys.join(", "))
+                })?);
+            }
+            "--min" | "-m" => {
+                let level = value(arg)?;
+                query.min = level.parse().ok()
+                    .filter(|l| (1..=MAX_LEVEL).contains(l))
+                    .ok_or_else(|| format!("--min takes a level from 1 to {}", MAX_LEVEL))?;
+            }
+            "--sort" | "-s" => {
+                query.sort = Some(match value(arg)? {
+                    "level" => Sort::Level,
+                    "name" => Sort::Name,
+                    other => return Err(format!("can't sort by '{}'", escape_html(other))),
+                });
+            }
+            other => return Err(format!("unexpected argument '{}'", escape_html(other))),
+        }
+    }
+    Ok(query)
+}
+
+/// Matches a category by its short key or (a prefix of) its display name.
+fn find_category(name: &str) -> Option<SkillCategory> {
+    let name = name.to_lowercase();
+    SkillCategory::ALL.iter().copied()
+        .find(|c| c.key() == name)
+        .or_else(|| SkillCategory::ALL.iter().copied().find(|c| c.name().to_lowercase().starts_with(&name)))
+}
+
+fn level_label(level: u8) -> &'static str {
+    match level {
+        0 | 1 => "Aware",
+        2 => "Beginner",
+        3 => "Intermediate",
+        4 => "Advanced",
+        _ => "Expert",
+    }
+}
+
+fn bar(level: Option<u8>) -> Span {
+    let Some(level) = level else {
+        return Span::new("—", "color: #888");
+    };
+    let filled = "█".repeat(level.min(MAX_LEVEL) as usize * CELLS_PER_LEVEL);
+    let empty = "░".repeat(MAX_LEVEL as usize * CELLS_PER_LEVEL - filled.chars().count());
+    Span::html(
+        format!("<span style='color: #0f0'>{}</span><span style='color: #444'>{}</span>", filled, empty),
+        &format!("{}{}", filled, empty),
+    )
+}
+
+/// Skill rows; names are padded to `width` so separate tables line up.
+fn table(skills: &[&Skill], width: usize, show_category: bool) -> String {
+    let mut table = Table::new().indent(3).align(3, Align::Right);
+    for skill in skills {
+        let years = skill.years.map_or_else(|| "—".to_string(), |y| format!("{}y", y));
+        let mut cells = vec![
+            Span::plain(&layout::pad(skill.name, width)),
+            bar(skill.level),
+            Span::new(skill.level.map_or("", level_label), "color: #888"),
+            Span::new(&years, "color: #888"),
+        ];
+        if show_category {
+            cells.push(Span::new(skill.category.name(), "color: #888"));
+        }
+        table.row(cells);
+    }
+    table.render()
+}
+
+fn render(query: &Query) -> String {
+    let mut skills: Vec<&Skill> = SKILLS.iter()
+        .filter(|s| query.category.is_none_or(|c| s.category == c))
+        .filter(|s| s.level.unwrap_or(0) >= query.min)
+        .collect();
+
+    let mut filters = Vec::new();
+    if let Some(category) = query.category {
+        filters.push(format!("category: {}", category.name()));
+    }
+    if query.min > 0 {
+        filters.push(format!("level ≥ {}", query.min));
+    }
+
+    let body = if skills.is_empty() {
+        "<span style='color: #888'>No skills match those filters.</span>".to_string()
+    } else {
+        let width = skills.iter().map(|s| layout::display_width(s.name)).max().unwrap_or(0);
+        match query.sort {
+            Some(sort) => {
+                skills.sort_by(|a, b| compare(sort, a, b));
+                filters.push(format!("sorted by {}", match sort {
+                    Sort::Level => "level",
+                    Sort::Name => "name",
+                }));
+                table(&skills, width, query.category.is_none())
+            }
+            None => SkillCategory::ALL.iter()
+                .filter_map(|&category| {
+                    let rows: Vec<&Skill> = skills.iter().copied().filter(|s| s.category == category).collect();
+                    (!rows.is_empty()).then(|| format!(
+                        "<span style='color: #f90; font-weight: bold'>{} {}:</span>\n{}",
+                        category.icon(), category.name(), table(&rows, width, false)
+                    ))
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+        }
+    };
+
+    let note = filters.join(" · ");
+    format!(
+        "
+{}
+
+{}
+
+<span style='color: #888'>💡 Try: skills --category security · skills --sort level · skills --min 3</span>
+{}
+",
+        layout::header_with_note("TECHNICAL SKILLS", (!note.is_empty()).then_some(note.as_str())), body, layout::footer()
+    )
+}
+
+/// Strongest first for level, unknown levels last (ties broken by name),
+/// A–Z for name.
+fn compare(sort: Sort, a: &Skill, b: &Skill) -> Ordering {
+    let by_name = a.name.to_lowercase().cmp(&b.name.to_lowercase());
+    match sort {
+        Sort::Level => b.level.cmp(&a.level).then(by_name),
+        Sort::Name => by_name,
+    }
+}
@@ -0,0 +1,92 @@
+//! Reveals an output block progressively instead of all at once.
+
+use web_sys::{window, Node};
+
+use crate::settings::Typing;
+
+/// Text nodes of one output block, emptied up front and refilled frame by frame.
+pub struct Stream {
+    segments: Vec<(Node, Vec<char>)>,
+    segment: usize,
+    shown: usize,
+    lines: bool,
+    /// Characters per millisecond.
+    rate: f64,
+    last_frame: Option<f64>,
+    budget: f64,
+}
+
+impl Stream {
+    pub fn new(block: &Node, typing: Typing, speed: u32) -> Stream {
+        let mut segments = Vec::new();
+        collect_text(block, &mut segments);
+        Stream {
+            segments,
+            segment: 0,
+            shown: 0,
+            lines: typing == Typing::Lines,
+            rate: f64::from(speed) / 1000.0,
+            last_frame: None,
+            budget: 0.0,
+        }
+    }
+
+    /// Reveals as much as the time since the previous frame allows; returns
+    /// `true` once everything is visible.
+    pub fn advance(&mut self, now: f64) -> bool {
+        let elapsed = self.last_frame.map_or(16.0, |last| now - last);
+        self.last_frame = Some(now);
+        self.budget += elapsed * self.rate;
+
+        while let Some((node, text)) = self.segments.get(self.segment) {
+            let remaining = text.len() - self.shown;
+            let step = if self.lines {
+                text[self.shown..].iter().position(|&c| c == '\n').map_or(remaining, |i| i + 1)
+            } else {
+                remaining.min(self.budget.floor() as usize)
+            };
+            if step == 0 || (self.lines && (step as f64) > self.budget) {
+                break;
+            }
+            self.budget -= step as f64;
+            self.shown += step;
+            node.set_text_content(Some(&text[..self.shown].iter().collect::<String>()));
+            if self.shown == text.len() {
+                self.segment += 1;
+                self.shown = 0;
+            }
+        }
+        self.segment >= self.segments.len()
+    }
+
+    /// Shows the rest of the block immediately.
+    pub fn finish(&mut self) {
+        for (node, text) in &self.segments[self.segment.min(self.segments.len())..] {
+            node.set_text_content(Some(&text.iter().collect::<String>()));
+        }
+        self.segment = self.segments.len();
+    }
+}
+
+fn collect_text(node: &Node, segments: &mut Vec<(Node, Vec<char>)>) {
+    let children = node.child_nodes();
+    for i in 0..children.length() {
+        let Some(child) = children.item(i) else { continue };
+        if child.node_type() == Node::TEXT_NODE {
+            let text: Vec<char> = child.text_content().unwrap_or_default().chars().collect();
+            if !text.is_empty() {
+                child.set_text_content(Some(""));
+                segments.push((child, text));
+            }
+        } else {
+            collect_text(&child, segments);
+        }
+    }
+}
+
+/// Honours the visitor's OS-level "reduce motion" preference.
+pub fn prefers_reduced_motion() -> bool {
+    window()
+        .and_then(|w| w.match_media("(prefers-reduced-motion: reduce)").ok().flatten())
+        .is_some_and(|m| m.matches())
+}
@@ -0,0 +1,178 @@
+//! `neofetch`, `version` and `uname`: the portfolio as the visitor is
+//! running it — their browser and screen, this session, and the build they
+//! were served (baked in by `build.rs`).
+
+use crate::browser;
+use crate::content::{EDUCATION, PROFILE};
+use crate::layout::{self, Span, Table};
+use crate::terminal::Terminal;
+
+include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+
+/// Logo for terminals at least as wide as its widest line.
+const ART: &str = "
+    █████╗ ██╗   ██╗███████╗████████╗███████╗███╗   ███╗
+   ██╔══██╗██║   ██║██╔════╝╚══██╔══╝██╔════╝████╗ ████║
+   ███████║██║   ██║███████╗   ██║   █████╗  ██╔██████║
+   ██╔══██║██║   ██║╚════██║   ██║   ██╔══╝  ██║╚██╔╝██║
+   ██║  ██║╚██████╝███████║   ██║   ███████╗██║ ╚═╝ ██║
+   ╚═╝  ╚═╝ ╚═════╝ ╚══════╝   ╚═╝   ╚══════╝╚═╝     ╚═╝
+";
+
+/// Logo for phones and narrow panes.
+const ART_COMPACT: &str = "
+ ▄▀█ █▄▀
+ █▀█ █░█
+";
+
+/// Browser tokens in the user agent, most specific first: Edge and Opera
+/// also claim to be Chrome, and Chrome claims to be Safari.
+const BROWSERS: [(&str, &str); 5] = [
+    ("Edg/", "Edge"),
+    ("OPR/", "Opera"),
+    ("Firefox/", "Firefox"),
+    ("Chrome/", "Chrome"),
+    ("Version/", "Safari"),
+];
+
+const SYSTEMS: [(&str, &str); 6] = [
+    ("Android", "Android"),
+    ("iPhone", "iOS"),
+    ("iPad", "iPadOS"),
+    ("CrOS", "ChromeOS"),
+    ("Windows", "Windows"),
+    ("Mac OS X", "macOS"),
+];
+
+/// Browser name and major version, e.g. "Firefox 131".
+fn browser_name(ua: &str) -> String {
+    BROWSERS.iter()
+        .find_map(|(token, name)| {
+            let version = ua.split(token).nth(1)?;
+            let major: String = version.chars().take_while(char::is_ascii_digit).collect();
+            Some(format!("{} {}", name, major).trim_end().to_string())
+        })
+        .unwrap_or_else(|| "Unknown browser".to_string())
+}
+
+fn system_name(ua: &str) -> &'static str {
+    SYSTEMS.iter()
+        .find(|(token, _)| ua.contains(token))
+        .map(|&(_, name)| name)
+        .unwrap_or(if ua.contains("Linux") { "Linux" } else { "Unknown OS" })
+}
+
+/// neofetch-style uptime: "1 hour, 5 mins".
+fn uptime(ms: f64) -> String {
+    let secs = (ms / 1000.0) as u64;
+    let plural = |n: u64, unit: &str| format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" });
+    let (hours, mins) = (secs / 3600, secs / 60 % 60);
+    match (hours, mins) {
+        (0, 0) => plural(secs, "sec"),
+        (0, m) => plural(m, "min"),
+        (h, 0) => plural(h, "hour"),
+        (h, m) => format!("{}, {}", plural(h, "hour"), plural(m, "min")),
+    }
+}
+
+fn file_size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{} B", bytes),
+        1024..1_048_576 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.2} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+/// Kernel name reported by `uname`.
+const KERNEL: &str = "PortfolioOS";
+
+/// "wasm32" from "wasm32-unknown-unknown".
+fn machine() -> &'static str {
+    TARGET.split('-').next().unwrap_or_default()
+}
+
+pub fn version_command() -> String {
+    let facts = layout::key_values("color: #0ff", vec![
+        ("commit".to_string(), Span::plain(&format!("{} ({})", GIT_HASH, GIT_DATE))),
+        ("built".to_string(), Span::plain(BUILT_AT)),
+        ("rustc".to_string(), Span::plain(RUSTC_VERSION)),
+        ("target".to_string(), Span::plain(TARGET)),
+    ]);
+    format!("<span style='color: #fff; font-weight: bold'>rust-portfolio {}</span>\n{}", VERSION, facts)
+}
+
+const UNAME_USAGE: &str = "<span style='color: #888'>Usage: uname [-asnrvmo]</span>";
+
+pub fn uname_command(args: &[&str]) -> Result<String, String> {
+    let release_version = format!("#{} {}", GIT_HASH, BUILT_AT);
+    // Fields in the order `uname -a` prints them.
+    let fields = [
+        ('s', KERNEL),
+        ('n', "portfolio"),
+        ('r', VERSION),
+        ('v', release_version.as_str()),
+        ('m', machine()),
+        ('o', "WebAssembly"),
+    ];
+    let mut wanted = Vec::new();
+    for arg in args {
+        match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() && flags.chars().all(|f| f == 'a' || fields.iter().any(|(c, _)| *c == f)) => {
+                wanted.extend(flags.chars());
+            }
+            _ => {
+                return Err(format!(
+                    "<span style='color: #f44'>uname: invalid option '{}'</span>\n{}",
+                    browser::escape_html(arg), UNAME_USAGE
+                ))
+            }
+        }
+    }
+    if wanted.is_empty() {
+        wanted.push('s');
+    }
+    let values: Vec<&str> = fields.iter()
+        .filter(|(flag, _)| wanted.contains(flag) || wanted.contains(&'a'))
+        .map(|&(_, value)| value)
+        .collect();
+    Ok(browser::escape_html(&values.join(" ")))
+}
+
+pub fn neofetch_command(term: &Terminal) -> String {
+    let ua = browser::user_agent();
+    let (width, height) = browser::viewport();
+    let wide = ART.lines().map(layout::display_width).max().unwrap_or(0) <= layout::columns();
+    let shell = term.var("SHELL").unwrap_or_default();
+
+    let info = [
+        ("OS", format!("Portfolio OS on {}", system_name(&ua))),
+        ("Host", browser_name(&ua)),
+        ("Kernel", format!("{} {} {}", KERNEL, VERSION, machine())),
+        ("Build", format!("{} · {}", GIT_HASH, BUILT_AT)),
+        ("Compiler", RUSTC_VERSION.to_string()),
+        ("Binary", browser::resource_size(".wasm").map(file_size).unwrap_or_else(|| "unknown".to_string())),
+        ("Uptime", uptime(browser::now())),
+        ("Commands", format!("{} this session", term.commands_run())),
+        ("Shell", shell.rsplit('/').next().unwrap_or_default().to_string()),
+        ("Resolution", format!("{}x{} · {} columns", width, height, term.columns())),
+        ("Theme", term.settings().theme.name().to_string()),
+        ("Education", format!("{} · {}", EDUCATION.short_name, EDUCATION.graduation())),
+        ("CGPA", EDUCATION.cgpa.to_string()),
+        ("Focus", PROFILE.focus.to_string()),
+    ];
+    let mut table = Table::new().gap(3);
+    for (key, value) in &info {
+        table.row(vec![Span::new(key, "color: #f90"), Span::plain(value)]);
+    }
+    format!("
+<span style='color: #ffc600'>{}</span>
+<span style='color: #0ff'>{}@portfolio</span>
+{}
+{}
+
+{}
+",
+        if wide { ART } else { ART_COMPACT }, browser::escape_html(&term.var("USER").unwrap_or_default()),
+        "─".repeat(layout::width() - 2), table.render(), layout::footer()
+    )
+}
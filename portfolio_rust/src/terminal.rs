@@ -0,0 +1,788 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, VecDeque};
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::prelude::*;
+use web_sys::{window, Element, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent, ResizeObserver};
+
+use futures::future::{self, AbortHandle, Abortable, FutureExt};
+
+use crate::browser;
+use crate::files;
+use crate::i18n;
+use crate::jobs::{self, JobState, JobTable, Progress, Task};
+use crate::layout;
+use crate::rc;
+use crate::record::{self, EventKind, Recording};
+use crate::script;
+use crate::settings::{Settings, Typing};
+use crate::stream::{self, Stream};
+use crate::vfs;
+
+type KeyHandler = Closure<dyn FnMut(KeyboardEvent)>;
+type ClickHandler = Closure<dyn FnMut(MouseEvent)>;
+type ResizeHandler = (ResizeObserver, Closure<dyn FnMut(JsValue)>);
+
+/// The job currently holding the terminal, with its spinner line.
+struct Foreground {
+    id: usize,
+    status: Element,
+    timer: i32,
+    _tick: Closure<dyn FnMut()>,
+}
+
+/// Runs before the terminal's own key handling; returns `true` when it
+/// consumed the event.
+pub type KeyHook = Rc<dyn Fn(&KeyboardEvent) -> bool>;
+
+/// One shell session: its own output pane, input line, history, env and cwd.
+/// Several can live on the same page, each mounted into its own container.
+pub struct Terminal {
+    me: Weak<Terminal>,
+    container: Element,
+    output: HtmlElement,
+    input: HtmlInputElement,
+    prompt: HtmlElement,
+    /// Ten hidden characters in the output font, for measuring `$COLUMNS`.
+    measure: HtmlElement,
+    history: RefCell<Vec<String>>,
+    history_index: Cell<isize>,
+    env: RefCell<BTreeMap<String, String>>,
+    aliases: RefCell<BTreeMap<String, String>>,
+    cwd: RefCell<String>,
+    keydown: RefCell<Option<KeyHandler>>,
+    click: RefCell<Option<ClickHandler>>,
+    resize: RefCell<Option<ResizeHandler>>,
+    key_hook: RefCell<Option<KeyHook>>,
+    mode: RefCell<Option<KeyHook>>,
+    recording: RefCell<Option<Recording>>,
+    last_recording: RefCell<Option<Recording>>,
+    replay_timers: RefCell<Vec<i32>>,
+    settings: RefCell<Settings>,
+    stream: RefCell<Option<Stream>>,
+    jobs: RefCell<JobTable>,
+    foreground: RefCell<Option<Foreground>>,
+    queue: RefCell<VecDeque<String>>,
+}
+
+impl Terminal {
+    /// Builds the output pane and input line inside `container` and wires up
+    /// the keyboard handler.
+    pub fn mount(container: &Element) -> Result<Rc<Terminal>, JsValue> {
+        let document = window()
+            .and_then(|w| w.document())
+            .ok_or_else(|| JsValue::from_str("no document"))?;
+
+        let output: HtmlElement = document.create_element("div")?.dyn_into()?;
+        output.set_class_name("terminal-output");
+
+        let input_line = document.create_element("div")?;
+        input_line.set_class_name("input-line");
+
+        let prompt: HtmlElement = document.create_element("span")?.dyn_into()?;
+        prompt.set_class_name("prompt");
+
+        let input: HtmlInputElement = document.create_element("input")?.dyn_into()?;
+        input.set_type("text");
+        input.set_class_name("command-input");
+        input.set_autocomplete("off");
+        input.set_spellcheck(false);
+        input.set_attribute("aria-label", "Terminal command input")?;
+
+        let measure: HtmlElement = document.create_element("span")?.dyn_into()?;
+        measure.set_class_name("terminal-output");
+        measure.set_attribute("aria-hidden", "true")?;
+        measure.set_attribute("style", "position: absolute; visibility: hidden; white-space: pre")?;
+        measure.set_text_content(Some("0000000000"));
+
+        input_line.append_child(&prompt)?;
+        input_line.append_child(&input)?;
+        container.append_child(&output)?;
+        container.append_child(&input_line)?;
+        container.append_child(&measure)?;
+
+        let mut env = BTreeMap::new();
+        env.insert("USER".to_string(), "ashish".to_string());
+        env.insert("HOME".to_string(), "/home/ashish".to_string());
+        env.insert("SHELL".to_string(), "/bin/portfolio-sh".to_string());
+        env.insert("TERM".to_string(), "xterm-256color".to_string());
+        env.insert("COLUMNS".to_string(), layout::columns().to_string());
+
+        let term = Rc::new_cyclic(|me| Terminal {
+            me: me.clone(),
+            container: container.clone(),
+            output,
+            input,
+            prompt,
+            measure,
+            history: RefCell::new(Vec::new()),
+            history_index: Cell::new(-1),
+            env: RefCell::new(env),
+            aliases: RefCell::new(BTreeMap::new()),
+            cwd: RefCell::new("~".to_string()),
+            keydown: RefCell::new(None),
+            click: RefCell::new(None),
+            resize: RefCell::new(None),
+            key_hook: RefCell::new(None),
+            mode: RefCell::new(None),
+            recording: RefCell::new(None),
+            last_recording: RefCell::new(None),
+            replay_timers: RefCell::new(Vec::new()),
+            settings: RefCell::new(Settings::default()),
+            stream: RefCell::new(None),
+            jobs: RefCell::new(JobTable::default()),
+            foreground: RefCell::new(None),
+            queue: RefCell::new(VecDeque::new()),
+        });
+        term.update_prompt();
+
+        let weak: Weak<Terminal> = Rc::downgrade(&term);
+        let closure = Closure::wrap(Box::new(move |event: KeyboardEvent| {
+            if let Some(term) = weak.upgrade() {
+                term.on_keydown(&event);
+            }
+        }) as Box<dyn FnMut(KeyboardEvent)>);
+        term.input
+            .add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref())?;
+        *term.keydown.borrow_mut() = Some(closure);
+
+        // Clicking (or pressing Enter on) an output element carrying a
+        // `data-command` attribute runs that command.
+        let weak: Weak<Terminal> = Rc::downgrade(&term);
+        let click = Closure::wrap(Box::new(move |event: MouseEvent| {
+            let Some(term) = weak.upgrade() else { return };
+            let command = event.target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|el| el.closest("[data-command]").ok().flatten())
+                .and_then(|el| el.get_attribute("data-command"));
+            if let Some(command) = command {
+                event.prevent_default();
+                term.run_command(&command);
+            }
+        }) as Box<dyn FnMut(MouseEvent)>);
+        term.output
+            .add_event_listener_with_callback("click", click.as_ref().unchecked_ref())?;
+        *term.click.borrow_mut() = Some(click);
+
+        // Keep `$COLUMNS` in step with the pane: window resizes, phone
+        // rotation, splitting or closing panes, opening the terminal.
+        let weak: Weak<Terminal> = Rc::downgrade(&term);
+        let on_resize = Closure::wrap(Box::new(move |_entries: JsValue| {
+            if let Some(term) = weak.upgrade() {
+                term.measure_columns();
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+        let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref())?;
+        observer.observe(&term.output);
+        *term.resize.borrow_mut() = Some((observer, on_resize));
+        term.measure_columns();
+
+        Ok(term)
+    }
+
+    /// Sets `$COLUMNS` to how many characters fit across the output. Does
+    /// nothing while the terminal is hidden and has no size.
+    fn measure_columns(&self) {
+        let char_width = self.measure.get_bounding_client_rect().width() / 10.0;
+        let width = self.output.client_width() as f64;
+        if char_width > 0.0 && width > 0.0 {
+            let columns = (width / char_width).floor() as usize;
+            self.set_var("COLUMNS", &columns.to_string());
+        }
+    }
+
+    /// Terminal width from `$COLUMNS`, which an `export` can override until
+    /// the next resize.
+    pub fn columns(&self) -> usize {
+        self.var("COLUMNS").and_then(|c| c.parse().ok()).unwrap_or(layout::WIDTH)
+    }
+
+    /// A strong handle to this terminal, for callbacks that outlive a borrow.
+    pub fn rc(&self) -> Rc<Terminal> {
+        self.me.upgrade().expect("terminal is alive while borrowed")
+    }
+
+    fn on_keydown(&self, event: &KeyboardEvent) {
+        if let Some(data) = record::key_to_input(&event.key(), event.ctrl_key()) {
+            self.record(EventKind::Input, data);
+        }
+
+        if self.finish_stream() && event.ctrl_key() && event.key() == "c" {
+            event.prevent_default();
+            return;
+        }
+
+        let foreground = self.foreground.borrow().as_ref().map(|f| f.id);
+        if let (true, Some(id)) = (event.ctrl_key() && event.key() == "c", foreground) {
+            event.prevent_default();
+            self.queue.borrow_mut().clear();
+            self.with_jobs(|jobs| {
+                if let Some(job) = jobs.get(id) {
+                    job.abort.abort();
+                }
+            });
+            return;
+        }
+
+        if event.ctrl_key() && event.key() == "c" && !self.replay_timers.borrow().is_empty() {
+            event.prevent_default();
+            self.stop_replay();
+            self.print_output_colored("<span style='color: #888'>^C Replay stopped</span>");
+            self.scroll_to_bottom();
+            return;
+        }
+
+        let hook = self.key_hook.borrow().clone();
+        if let Some(hook) = hook {
+            if hook(event) {
+                return;
+            }
+        }
+
+        let mode = self.mode.borrow().clone();
+        if let Some(mode) = mode {
+            if mode(event) {
+                return;
+            }
+        }
+
+        match event.key().as_str() {
+            "Enter" => self.handle_command(),
+            "ArrowUp" => {
+                event.prevent_default();
+                self.navigate_history(1);
+            }
+            "ArrowDown" => {
+                event.prevent_default();
+                self.navigate_history(-1);
+            }
+            // Shift+Tab keeps its usual meaning so links in the output
+            // stay reachable from the keyboard.
+            "Tab" if !event.shift_key() => {
+                event.prevent_default();
+                self.autocomplete();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn set_key_hook(&self, hook: KeyHook) {
+        *self.key_hook.borrow_mut() = Some(hook);
+    }
+
+    /// Hands the keyboard to an interactive mode (tour, prompts...) until
+    /// [`Terminal::clear_mode`] is called.
+    pub fn set_mode(&self, mode: KeyHook) {
+        *self.mode.borrow_mut() = Some(mode);
+    }
+
+    pub fn clear_mode(&self) {
+        *self.mode.borrow_mut() = None;
+    }
+
+    pub fn set_input(&self, text: &str) {
+        self.input.set_value(text);
+    }
+
+    pub fn input_value(&self) -> String {
+        self.input.value()
+    }
+
+    pub fn focus(&self) {
+        let _ = self.input.focus();
+    }
+
+    /// First output of a new terminal: whatever `~/.portfoliorc` prints,
+    /// then the welcome.
+    pub fn start(&self) {
+        layout::set_columns(self.columns());
+        let output = rc::run(self);
+        if !output.is_empty() {
+            self.print_output(&output);
+        }
+        self.print_welcome();
+    }
+
+    pub fn print_welcome(&self) {
+        layout::set_columns(self.columns());
+        let title = layout::Span::new(&i18n::t("welcome-title"), "color: #ffc600; font-weight: bold");
+        self.print_output_colored(&format!("
+{}
+
+<span style='color: #0f0'>{}</span>
+<span style='color: #888'>   {}</span>
+", layout::panel(&[vec![title]], "color: #0ff; font-size: 1.2em"), i18n::t("welcome-hint"), i18n::t("welcome-tour")));
+    }
+
+    /// Types `command` into the prompt and runs it, unless an interactive
+    /// mode currently owns the input.
+    pub fn run_command(&self, command: &str) {
+        if self.mode.borrow().is_some() {
+            return;
+        }
+        self.set_input(command);
+        self.handle_command();
+    }
+
+    /// Runs what is typed at the prompt and clears it.
+    pub fn handle_command(&self) {
+        let command = self.input.value().trim().to_string();
+        let queued = self.foreground.borrow().is_some();
+        self.run_line(&command);
+        self.input.set_value("");
+        if !queued {
+            self.focus();
+        }
+    }
+
+    /// Runs `command` as if it had been entered, leaving the input line
+    /// alone so queued commands don't disturb what the visitor is typing.
+    fn run_line(&self, command: &str) {
+        // While a foreground job runs, further input waits its turn.
+        if self.foreground.borrow().is_some() {
+            if !command.is_empty() {
+                self.print_output(&format!("<span style='color: #888'>(queued) {}</span>", browser::escape_html(command)));
+                self.queue.borrow_mut().push_back(command.to_string());
+            }
+            self.scroll_to_bottom();
+            return;
+        }
+
+        if !command.is_empty() {
+            let mut history = self.history.borrow_mut();
+            history.push(command.to_string());
+            self.history_index.set(history.len() as isize);
+        }
+
+        self.print_output(&format!("<span style='color: #0f0'>{}</span> {}", browser::escape_html(&self.prompt_text()), command));
+
+        let (line, background) = jobs::strip_background(command);
+        let line = self.expand_alias(line);
+        let line = line.as_str();
+        // Async commands finish after this returns, so there is nothing to
+        // redirect yet.
+        let redirect = files::split_redirect(line);
+        let command = match &redirect {
+            Some(Ok(redirect)) => redirect.command,
+            _ => line,
+        };
+        let words = script::expand(self, command);
+        let parts: Vec<&str> = words.iter().map(String::as_str).collect();
+        let progress = Progress::new(line);
+        if let Some(task) = jobs::async_command(&parts, &progress) {
+            if redirect.is_some() {
+                self.print_output(&format!(
+                    "<span style='color: #f44'>{}: output redirection is not supported</span>",
+                    browser::escape_html(parts[0])
+                ));
+            } else {
+                self.spawn_job(line, task, progress, background);
+            }
+        } else {
+            layout::set_columns(self.columns());
+            let response = crate::process_command(self, line).unwrap_or_else(|e| e);
+            if background {
+                self.spawn_job(line, future::ready(response).boxed_local(), progress, true);
+            } else if !response.is_empty() {
+                self.print_streamed(&response);
+            }
+        }
+
+        self.scroll_to_bottom();
+    }
+
+    pub fn with_jobs<R>(&self, f: impl FnOnce(&JobTable) -> R) -> R {
+        f(&self.jobs.borrow())
+    }
+
+    pub fn with_jobs_mut<R>(&self, f: impl FnOnce(&mut JobTable) -> R) -> R {
+        f(&mut self.jobs.borrow_mut())
+    }
+
+    /// Adds `task` to the job table and starts it, either behind a spinner in
+    /// the foreground or silently in the background.
+    fn spawn_job(&self, command: &str, task: Task, progress: Progress, background: bool) {
+        let (abort, registration) = AbortHandle::new_pair();
+        let id = self.jobs.borrow_mut().add(command, abort, progress);
+        if background {
+            self.print_output(&format!("[{}] {}", id, browser::escape_html(command)));
+        } else {
+            self.attach_job(id);
+        }
+
+        let me = self.me.clone();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = Abortable::new(task, registration).await;
+            if let Some(term) = me.upgrade() {
+                term.job_finished(id, result.ok());
+            }
+        });
+    }
+
+    /// Brings job `id` to the foreground (`fg`): a finished job prints its
+    /// held-back output, a running one gets the spinner and the keyboard.
+    pub fn attach_job(&self, id: usize) {
+        let Some((command, state, progress)) = self.with_jobs(|jobs| {
+            jobs.get(id).map(|j| (j.command.clone(), j.state, j.progress.clone()))
+        }) else { return };
+
+        if state != JobState::Running {
+            let output = self.jobs.borrow_mut().remove(id).and_then(|j| j.output);
+            match output {
+                Some(output) if !output.is_empty() => self.print_streamed(&output),
+                Some(_) => {}
+                None => self.print_output(&format!("[{}]+  Terminated  {}", id, browser::escape_html(&command))),
+            }
+            return;
+        }
+
+        self.finish_stream();
+        let Some(status) = window().and_then(|w| w.document()).and_then(|d| d.create_element("div").ok()) else { return };
+        status.set_class_name("job-status");
+        if self.output.append_child(&status).is_err() {
+            return;
+        }
+
+        let frame = Cell::new(0);
+        let spinner = status.clone();
+        let render = move || {
+            let i = frame.get();
+            frame.set((i + 1) % jobs::SPINNER.len());
+            spinner.set_inner_html(&format!(
+                "<span style='color: #ffc600'>{}</span> <span style='color: #888'>{}</span>",
+                jobs::SPINNER[i], browser::escape_html(&progress.get())
+            ));
+        };
+        render();
+        let tick = Closure::wrap(Box::new(render) as Box<dyn FnMut()>);
+        let timer = window()
+            .and_then(|w| {
+                w.set_interval_with_callback_and_timeout_and_arguments_0(tick.as_ref().unchecked_ref(), 80).ok()
+            })
+            .unwrap_or_default();
+
+        *self.foreground.borrow_mut() = Some(Foreground { id, status, timer, _tick: tick });
+        self.input.class_list().add_1("busy").ok();
+    }
+
+    /// Runs `task` in the foreground on behalf of an interactive command that
+    /// has already echoed its own input.
+    pub fn run_job(&self, command: &str, task: Task) {
+        self.spawn_job(command, task, Progress::new(command), false);
+    }
+
+    fn job_finished(&self, id: usize, result: Option<String>) {
+        let in_foreground = self.foreground.borrow().as_ref().is_some_and(|f| f.id == id);
+        if !in_foreground {
+            let command = self.with_jobs_mut(|jobs| {
+                let job = jobs.get_mut(id)?;
+                job.state = if result.is_some() { JobState::Done } else { JobState::Killed };
+                job.output = result;
+                Some((job.command.clone(), job.state))
+            });
+            if let Some((command, state)) = command {
+                let label = if state == JobState::Done { "Done" } else { "Terminated" };
+                self.print_output(&format!(
+                    "<span style='color: #888'>[{}]+  {:<10} {}</span>", id, label, browser::escape_html(&command)
+                ));
+                self.scroll_to_bottom();
+            }
+            return;
+        }
+
+        if let Some(fg) = self.foreground.borrow_mut().take() {
+            if let Some(w) = window() {
+                w.clear_interval_with_handle(fg.timer);
+            }
+            fg.status.remove();
+        }
+        self.jobs.borrow_mut().remove(id);
+        self.input.class_list().remove_1("busy").ok();
+
+        match result {
+            Some(response) if !response.is_empty() => self.print_streamed(&response),
+            Some(_) => {}
+            None => self.print_output("<span style='color: #888'>^C</span>"),
+        }
+        self.scroll_to_bottom();
+
+        let next = self.queue.borrow_mut().pop_front();
+        if let Some(next) = next {
+            self.run_line(&next);
+        }
+    }
+
+    fn navigate_history(&self, direction: isize) {
+        let history = self.history.borrow();
+        if history.is_empty() { return; }
+
+        let mut index = self.history_index.get() + direction;
+
+        if index < 0 {
+            index = -1;
+            self.input.set_value("");
+        } else if (index as usize) >= history.len() {
+            index = (history.len() - 1) as isize;
+            self.input.set_value(&history[index as usize]);
+        } else {
+            self.input.set_value(&history[index as usize]);
+        }
+        self.history_index.set(index);
+    }
+
+    fn autocomplete(&self) {
+        let current = self.input.value();
+        let commands = vec!["help", "whoami", "skills", "education", "projects", "contact", "neofetch", "clear", "echo", "certifications", "pwd", "env", "export", "record", "replay", "tour", "set", "sleep", "curl", "jobs", "fg", "kill", "gh", "resume", "open", "mail", "search", "timeline", "lang", "version", "uname", "nano", "cd", "touch", "mkdir", "rm", "mv", "cp", "sh", "alias", "unalias"];
+
+        for cmd in commands {
+            if cmd.starts_with(&current) {
+                self.input.set_value(cmd);
+                break;
+            }
+        }
+    }
+
+    pub fn print_output(&self, text: &str) {
+        self.append_block(text);
+    }
+
+    pub fn print_output_colored(&self, html: &str) {
+        self.append_block(html);
+    }
+
+    /// Prints `html` and hands back its block so it can be redrawn in place
+    /// (only the first rendering is recorded).
+    pub fn print_live(&self, html: &str) -> Option<Element> {
+        self.append_block(html)?.dyn_into().ok()
+    }
+
+    /// Prints a command's response, revealing it gradually when the typing
+    /// animation is enabled.
+    pub fn print_streamed(&self, html: &str) {
+        let settings = self.settings();
+        let Some(block) = self.append_block(html) else { return };
+        if settings.typing == Typing::Off || stream::prefers_reduced_motion() {
+            return;
+        }
+        *self.stream.borrow_mut() = Some(Stream::new(&block, settings.typing, settings.typing_speed));
+        self.request_stream_frame();
+    }
+
+    fn append_block(&self, html: &str) -> Option<web_sys::Node> {
+        // A new block must not appear above the tail of one still streaming.
+        self.finish_stream();
+        self.record(EventKind::Output, record::html_to_ansi(html) + "\r\n");
+        let block = window()?.document()?.create_element("div").ok()?;
+        block.set_inner_html(html);
+        self.output.append_child(&block).ok()
+    }
+
+    fn request_stream_frame(&self) {
+        let me = self.me.clone();
+        let callback = Closure::once_into_js(move |now: f64| {
+            if let Some(term) = me.upgrade() {
+                term.stream_frame(now);
+            }
+        });
+        if let Some(w) = window() {
+            let _ = w.request_animation_frame(callback.unchecked_ref());
+        }
+    }
+
+    fn stream_frame(&self, now: f64) {
+        let done = match self.stream.borrow_mut().as_mut() {
+            Some(stream) => stream.advance(now),
+            None => return,
+        };
+        self.scroll_to_bottom();
+        if done {
+            *self.stream.borrow_mut() = None;
+        } else {
+            self.request_stream_frame();
+        }
+    }
+
+    /// Reveals whatever is still streaming; returns `true` if anything was.
+    fn finish_stream(&self) -> bool {
+        let Some(mut stream) = self.stream.borrow_mut().take() else { return false };
+        stream.finish();
+        self.scroll_to_bottom();
+        true
+    }
+
+    pub fn settings(&self) -> Settings {
+        self.settings.borrow().clone()
+    }
+
+    pub fn update_settings(&self, f: impl FnOnce(&mut Settings)) {
+        f(&mut self.settings.borrow_mut());
+        let _ = self.container.set_attribute("data-theme", self.settings.borrow().theme.key());
+    }
+
+    /// Commands entered in this terminal since it opened.
+    pub fn commands_run(&self) -> usize {
+        self.history.borrow().len()
+    }
+
+    fn record(&self, kind: EventKind, data: String) {
+        if let Some(rec) = self.recording.borrow_mut().as_mut() {
+            rec.push(kind, data);
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.borrow().is_some()
+    }
+
+    pub fn start_recording(&self) {
+        *self.recording.borrow_mut() = Some(Recording::new());
+    }
+
+    pub fn stop_recording(&self) -> Option<Recording> {
+        let rec = self.recording.borrow_mut().take()?;
+        *self.last_recording.borrow_mut() = Some(rec.clone());
+        Some(rec)
+    }
+
+    pub fn last_recording(&self) -> Option<Recording> {
+        self.last_recording.borrow().clone()
+    }
+
+    pub fn set_replay_timers(&self, timers: Vec<i32>) {
+        *self.replay_timers.borrow_mut() = timers;
+    }
+
+    /// Cancels any replay still scheduled on this terminal.
+    pub fn stop_replay(&self) {
+        for id in self.replay_timers.borrow_mut().drain(..) {
+            browser::clear_timeout(id);
+        }
+    }
+
+    pub fn finish_replay(&self) {
+        self.replay_timers.borrow_mut().clear();
+    }
+
+    pub fn clear(&self) {
+        self.stream.borrow_mut().take();
+        self.output.set_inner_html("");
+    }
+
+    pub fn scroll_to_bottom(&self) {
+        self.container.set_scroll_top(self.container.scroll_height());
+    }
+
+    /// The prompt: `$PS1` if set, with bash's `\u`, `\h`, `\w`, `\W` and
+    /// `\$` escapes, else `user@portfolio:cwd$`.
+    pub fn prompt_text(&self) -> String {
+        let user = self.var("USER").unwrap_or_default();
+        let cwd = self.cwd.borrow();
+        let Some(ps1) = self.var("PS1") else {
+            return format!("{}@portfolio:{}$", user, cwd);
+        };
+        let mut prompt = String::new();
+        let mut chars = ps1.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                prompt.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('u') => prompt.push_str(&user),
+                Some('h') => prompt.push_str("portfolio"),
+                Some('w') => prompt.push_str(&cwd),
+                Some('W') => prompt.push_str(cwd.rsplit('/').next().filter(|dir| !dir.is_empty()).unwrap_or(&cwd)),
+                Some('$') => prompt.push('$'),
+                Some(other) => {
+                    prompt.push('\\');
+                    prompt.push(other);
+                }
+                None => prompt.push('\\'),
+            }
+        }
+        prompt
+    }
+
+    /// Changes the working directory to the absolute `path`.
+    pub fn set_cwd(&self, path: &str) {
+        *self.cwd.borrow_mut() = vfs::display(path);
+        self.update_prompt();
+    }
+
+    fn update_prompt(&self) {
+        self.prompt.set_text_content(Some(&self.prompt_text()));
+    }
+
+    /// Shows `text` in place of the shell prompt while a command asks for input.
+    pub fn set_prompt(&self, text: &str) {
+        self.prompt.set_text_content(Some(text));
+    }
+
+    pub fn reset_prompt(&self) {
+        self.update_prompt();
+    }
+
+    pub fn var(&self, name: &str) -> Option<String> {
+        match name {
+            "PWD" => Some(vfs::resolve("/", &self.cwd.borrow())),
+            _ => self.env.borrow().get(name).cloned(),
+        }
+    }
+
+    pub fn set_var(&self, name: &str, value: &str) {
+        self.env.borrow_mut().insert(name.to_string(), value.to_string());
+        self.update_prompt();
+    }
+
+    pub fn alias(&self, name: &str) -> Option<String> {
+        self.aliases.borrow().get(name).cloned()
+    }
+
+    pub fn set_alias(&self, name: &str, value: &str) {
+        self.aliases.borrow_mut().insert(name.to_string(), value.to_string());
+    }
+
+    /// `false` if there was no such alias.
+    pub fn remove_alias(&self, name: &str) -> bool {
+        self.aliases.borrow_mut().remove(name).is_some()
+    }
+
+    pub fn aliases(&self) -> Vec<(String, String)> {
+        self.aliases.borrow().iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    }
+
+    /// `line` with an alias in its first word replaced by what it stands for.
+    fn expand_alias(&self, line: &str) -> String {
+        let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        match self.alias(first) {
+            Some(value) if rest.is_empty() => value,
+            Some(value) => format!("{} {}", value, rest),
+            None => line.to_string(),
+        }
+    }
+
+    pub fn vars(&self) -> Vec<(String, String)> {
+        let mut vars: Vec<(String, String)> = self.env.borrow().iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        vars.push(("PWD".to_string(), self.var("PWD").unwrap_or_default()));
+        vars.sort();
+        vars
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if let Some(closure) = self.keydown.borrow_mut().take() {
+            let _ = self.input
+                .remove_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
+        }
+        if let Some(closure) = self.click.borrow_mut().take() {
+            let _ = self.output
+                .remove_event_listener_with_callback("click", closure.as_ref().unchecked_ref());
+        }
+        if let Some((observer, _)) = self.resize.borrow_mut().take() {
+            observer.disconnect();
+        }
+    }
+}
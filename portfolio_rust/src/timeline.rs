@@ -0,0 +1,226 @@
+//! `timeline`: education, projects and certifications as a chronological
+//! ASCII Gantt chart, one column per month, or several months per column
+//! when the terminal is too narrow for that.
+
+use crate::browser::escape_html;
+use crate::content::{DateRange, YearMonth, CERTIFICATIONS, EDUCATION, PROJECTS};
+use crate::layout;
+
+const USAGE: &str = "<span style='color: #888'>Usage: timeline [--year YYYY]</span>";
+
+/// Labels longer than this are cut short.
+const MAX_LABEL: usize = 30;
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Education,
+    Project,
+    Certification,
+}
+
+impl Kind {
+    fn icon(self) -> &'static str {
+        match self {
+            Kind::Education => "🎓",
+            Kind::Project => "📁",
+            Kind::Certification => "📜",
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Kind::Education => "#0ff",
+            Kind::Project => "#0f0",
+            Kind::Certification => "#ffc600",
+        }
+    }
+}
+
+/// How precisely an entry's dates are known, which decides how it is drawn.
+#[derive(Clone, Copy, PartialEq)]
+enum Span {
+    /// Month-accurate range.
+    Months,
+    /// Only the year is known (completed certifications).
+    Year,
+    /// Month-accurate end, but only the start year is known (education);
+    /// that first year is drawn as year only.
+    FromYear,
+    /// Still in progress; drawn at the current month.
+    Ongoing,
+}
+
+struct Entry {
+    kind: Kind,
+    label: String,
+    dates: DateRange,
+    span: Span,
+    when: String,
+}
+
+fn entries(today: YearMonth) -> Vec<Entry> {
+    let mut entries = vec![Entry {
+        kind: Kind::Education,
+        label: EDUCATION.short_name.to_string(),
+        dates: DateRange::new(YearMonth::new(EDUCATION.started, 1), EDUCATION.graduates),
+        span: Span::FromYear,
+        when: format!("{} - {}", EDUCATION.started, EDUCATION.graduates),
+    }];
+    entries.extend(PROJECTS.iter().map(|p| Entry {
+        kind: Kind::Project,
+        label: p.tagline.to_string(),
+        dates: p.dates,
+        span: Span::Months,
+        when: p.dates.to_string(),
+    }));
+    entries.extend(CERTIFICATIONS.iter().map(|c| match c.year {
+        Some(year) => Entry {
+            kind: Kind::Certification,
+            label: c.name.to_string(),
+            dates: DateRange::new(YearMonth::new(year, 1), YearMonth::new(year, 12)),
+            span: Span::Year,
+            when: year.to_string(),
+        },
+        None => Entry {
+            kind: Kind::Certification,
+            label: c.name.to_string(),
+            dates: DateRange::new(today, today),
+            span: Span::Ongoing,
+            when: "in progress".to_string(),
+        },
+    }));
+    entries.sort_by_key(|e| (e.dates.start, e.dates.end));
+    entries
+}
+
+pub fn timeline_command(args: &[&str]) -> Result<String, String> {
+    let year = match args {
+        [] => None,
+        ["--year" | "-y", year] | [year] => match year.parse::<u16>() {
+            Ok(year) if (1000..=9999).contains(&year) => Some(year),
+            _ => return Err(format!("<span style='color: #f44'>timeline: '{}' is not a year</span>\n{}", escape_html(year), USAGE)),
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let today = YearMonth::today();
+    let entries: Vec<Entry> = entries(today)
+        .into_iter()
+        .filter(|e| year.is_none_or(|y| e.dates.overlaps_year(y)))
+        .collect();
+    if entries.is_empty() {
+        return Ok(format!(
+            "<span style='color: #888'>Nothing on the timeline in {}.</span>",
+            year.map(|y| y.to_string()).unwrap_or_default()
+        ));
+    }
+
+    // The axis spans whole years; a single year gets wider month columns.
+    let (first, last) = match year {
+        Some(y) => (y, y),
+        None => (
+            entries.iter().map(|e| e.dates.start.year).min().unwrap_or(today.year),
+            entries.iter().map(|e| e.dates.end.year).max().unwrap_or(today.year),
+        ),
+    };
+    let axis_start = YearMonth::new(first, 1).index();
+    let months = (last - first + 1) as i32 * 12;
+    let mut cell = if year.is_some() { 3 } else { 1 };
+
+    let label_width = entries.iter().map(|e| layout::display_width(&layout::truncate(&e.label, MAX_LABEL))).max().unwrap_or(0);
+    let when_width = entries.iter().map(|e| layout::display_width(&e.when)).max().unwrap_or(0);
+    // Icon (two columns wide), a space, the label and three spaces. When
+    // that leaves no room for the bars, each bar goes under its label.
+    let wide = label_width + 6 + months as usize * cell + 2 + when_width <= layout::columns();
+    let gutter = " ".repeat(if wide { label_width + 6 } else { 3 });
+
+    // Months per column. Bars under their labels still have to fit, so a
+    // narrow terminal gets thinner month columns, or whole quarters and
+    // half-years per column; steps divide 12 so years stay aligned.
+    let mut step = 1;
+    if !wide {
+        let room = layout::columns().saturating_sub(gutter.len()).max(1);
+        if year.is_some() {
+            cell = (room / 12).clamp(1, cell);
+        } else {
+            step = [1, 2, 3, 4, 6, 12].into_iter().find(|s| (months / s) as usize <= room).unwrap_or(12);
+        }
+    }
+
+    let mut header = String::new();
+    if year.is_some() {
+        for month in 0..12 {
+            let name = YearMonth::from_index(axis_start + month).to_string();
+            header.push_str(&format!("{:<width$}", &name[..1], width = cell));
+        }
+    } else {
+        // Years too close together to label each one skip to the next
+        // free column.
+        let year_width = (12 / step) as usize;
+        for (i, y) in (first..=last).enumerate() {
+            let at = i * year_width;
+            if header.len() <= at {
+                header.push_str(&" ".repeat(at - header.len()));
+                header.push_str(&format!("{} ", y));
+            }
+        }
+        header.truncate(header.trim_end().len());
+    }
+
+    let rows: Vec<String> = entries.iter()
+        .map(|e| {
+            let bar: String = (0..months / step)
+                .map(|column| {
+                    // A column is drawn as its first month inside the entry,
+                    // or as today's marker if it has neither.
+                    let first_month = axis_start + column * step;
+                    let span = first_month..first_month + step;
+                    let month = span.clone()
+                        .find(|&m| e.dates.start.index() <= m && m <= e.dates.end.index())
+                        .unwrap_or(if span.contains(&today.index()) { today.index() } else { first_month });
+                    let inside = e.dates.start.index() <= month && month <= e.dates.end.index();
+                    let (glyph, color) = match (inside, e.span) {
+                        (true, Span::Year) => ("▒", e.kind.color()),
+                        (true, Span::FromYear) if YearMonth::from_index(month).year == e.dates.start.year => ("▒", e.kind.color()),
+                        (true, Span::Ongoing) => ("▶", e.kind.color()),
+                        (true, Span::Months | Span::FromYear) if month > today.index() => ("░", e.kind.color()),
+                        (true, Span::Months | Span::FromYear) => ("█", e.kind.color()),
+                        (false, _) if month == today.index() => ("┊", "#555"),
+                        (false, _) => return " ".repeat(cell),
+                    };
+                    format!("<span style='color: {}'>{}</span>", color, glyph.repeat(cell))
+                })
+                .collect();
+            let label = layout::truncate(&e.label, MAX_LABEL);
+            if wide {
+                format!(
+                    "{} {}   {}  <span style='color: #888'>{}</span>",
+                    e.kind.icon(), escape_html(&layout::pad(&label, label_width)), bar, e.when
+                )
+            } else {
+                format!(
+                    "{} {}  <span style='color: #888'>{}</span>\n{}{}",
+                    e.kind.icon(), escape_html(&label), e.when, gutter, bar
+                )
+            }
+        })
+        .collect();
+
+    let title = match year {
+        Some(y) => format!("TIMELINE · {}", y),
+        None => "TIMELINE".to_string(),
+    };
+    Ok(format!(
+        "
+{}
+
+<span style='color: #888'>{}{}</span>
+{}
+
+<span style='color: #888'>█ done  ░ planned  ▒ year only  ▶ in progress  ┊ today ({})</span>
+<span style='color: #888'>💡 Zoom in with: timeline --year {}</span>
+{}
+",
+        layout::header(&title), gutter, header, rows.join("\n"), today, year.unwrap_or(today.year), layout::footer()
+    ))
+}
@@ -0,0 +1,162 @@
+//! `tour`: a scripted walkthrough that types commands for the visitor.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use web_sys::KeyboardEvent;
+
+use crate::browser;
+use crate::terminal::{KeyHook, Terminal};
+
+pub struct TourStep {
+    pub command: &'static str,
+    pub note: &'static str,
+}
+
+pub const TOUR: &[TourStep] = &[
+    TourStep { command: "whoami", note: "Let's start with a quick introduction." },
+    TourStep { command: "skills", note: "Here's the toolbox: security tools, languages and ML libraries." },
+    TourStep { command: "projects", note: "Projects live in a little directory tree..." },
+    TourStep { command: "cat projects/ids", note: "...and 'cat' opens one. This is the intrusion detection system." },
+    TourStep { command: "certifications", note: "Courses completed and in progress." },
+    TourStep { command: "neofetch", note: "Every terminal needs a neofetch." },
+    TourStep { command: "contact", note: "Finally, how to get in touch." },
+];
+
+/// Milliseconds between typed characters, before jitter.
+const TYPING_DELAY: f64 = 45.0;
+
+struct TourState {
+    step: usize,
+    typed: usize,
+    timer: Option<i32>,
+    done: bool,
+}
+
+pub fn tour_command(term: &Terminal, args: &[&str]) -> Result<String, String> {
+    if let Some(arg) = args.first() {
+        return Err(format!("<span style='color: #f44'>tour: unexpected argument '{}'</span>\n<span style='color: #888'>Usage: tour</span>", browser::escape_html(arg)));
+    }
+
+    let state = Rc::new(RefCell::new(TourState { step: 0, typed: 0, timer: None, done: false }));
+    let me = Rc::downgrade(&term.rc());
+    let hook_state = state.clone();
+    let hook_term = me.clone();
+    let hook: KeyHook = Rc::new(move |event: &KeyboardEvent| {
+        if let Some(term) = hook_term.upgrade() {
+            on_key(&term, &hook_state, event);
+        }
+        true
+    });
+    term.set_mode(hook);
+
+    let first = me.clone();
+    browser::set_timeout(move || {
+        if let Some(term) = first.upgrade() {
+            begin_step(&term, &state);
+        }
+    }, 0);
+
+    Ok(format!(
+        "<span style='color: #ffc600; font-weight: bold'>🧭 Guided tour</span> <span style='color: #888'>({} stops)</span>\n\
+<span style='color: #0f0'>Enter</span> run step   <span style='color: #0f0'>n</span> skip to next   <span style='color: #0f0'>q</span> quit",
+        TOUR.len()
+    ))
+}
+
+fn begin_step(term: &Rc<Terminal>, state: &Rc<RefCell<TourState>>) {
+    let step = state.borrow().step;
+    if state.borrow().done {
+        return;
+    }
+    let Some(current) = TOUR.get(step) else {
+        finish(term, state, "<span style='color: #0f0'>✅ That's the tour! Type 'help' to explore on your own.</span>");
+        return;
+    };
+    state.borrow_mut().typed = 0;
+    term.set_input("");
+    term.print_output_colored(&format!(
+        "<span style='color: #888'>[{}/{}]</span> <span style='color: #0ff'>{}</span>",
+        step + 1, TOUR.len(), current.note
+    ));
+    term.scroll_to_bottom();
+    type_next(Rc::downgrade(term), state.clone());
+}
+
+fn type_next(term: Weak<Terminal>, state: Rc<RefCell<TourState>>) {
+    let Some(strong) = term.upgrade() else { return };
+    let (command, typed) = {
+        let s = state.borrow();
+        match TOUR.get(s.step) {
+            Some(step) if !s.done => (step.command, s.typed),
+            _ => return,
+        }
+    };
+    if typed >= command.len() {
+        state.borrow_mut().timer = None;
+        return;
+    }
+
+    let next = command[typed..].chars().next().map_or(1, char::len_utf8);
+    strong.set_input(&command[..typed + next]);
+    state.borrow_mut().typed = typed + next;
+
+    let delay = TYPING_DELAY + js_sys::Math::random() * 2.0 * TYPING_DELAY;
+    let timer_state = state.clone();
+    let id = browser::set_timeout(move || type_next(term, timer_state), delay as i32);
+    state.borrow_mut().timer = Some(id);
+}
+
+/// Stops the typing animation and shows the whole command.
+fn complete_typing(term: &Terminal, state: &Rc<RefCell<TourState>>) {
+    let mut s = state.borrow_mut();
+    if let Some(id) = s.timer.take() {
+        browser::clear_timeout(id);
+    }
+    if let Some(step) = TOUR.get(s.step) {
+        s.typed = step.command.len();
+        term.set_input(step.command);
+    }
+}
+
+fn on_key(term: &Rc<Terminal>, state: &Rc<RefCell<TourState>>, event: &KeyboardEvent) {
+    event.prevent_default();
+    let typing = state.borrow().timer.is_some();
+
+    match event.key().as_str() {
+        "q" | "Q" | "Escape" => {
+            finish(term, state, "<span style='color: #888'>Tour ended. Type 'tour' to start again.</span>");
+        }
+        "c" if event.ctrl_key() => {
+            finish(term, state, "<span style='color: #888'>^C Tour ended.</span>");
+        }
+        "n" | "N" | "ArrowRight" => {
+            complete_typing(term, state);
+            state.borrow_mut().step += 1;
+            term.set_input("");
+            begin_step(term, state);
+        }
+        "Enter" => {
+            complete_typing(term, state);
+            term.handle_command();
+            state.borrow_mut().step += 1;
+            begin_step(term, state);
+        }
+        _ if typing => complete_typing(term, state),
+        _ => {}
+    }
+}
+
+fn finish(term: &Terminal, state: &Rc<RefCell<TourState>>, message: &str) {
+    {
+        let mut s = state.borrow_mut();
+        if let Some(id) = s.timer.take() {
+            browser::clear_timeout(id);
+        }
+        s.done = true;
+    }
+    term.clear_mode();
+    term.set_input("");
+    term.print_output_colored(message);
+    term.scroll_to_bottom();
+}
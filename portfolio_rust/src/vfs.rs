@@ -0,0 +1,393 @@
+//! The virtual filesystem: the portfolio's content as read-only files
+//! (`~/projects/*`, `~/resume.md`, `~/about.txt`) with the visitor's own
+//! files and directories layered on top. The top layer is kept in
+//! `localStorage`, so it survives a reload.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use wasm_bindgen::JsValue;
+
+use crate::browser;
+use crate::content::{PROFILE, PROJECTS};
+use crate::{projects, resume, script};
+
+pub const HOME: &str = "/home/ashish";
+const SCRATCH: &str = "/home/ashish/scratch";
+
+/// Read-only directories visitors may still create entries in.
+const WRITABLE_DIRS: [&str; 2] = [HOME, SCRATCH];
+
+const STORAGE_KEY: &str = "portfolio-fs";
+
+#[derive(Clone)]
+enum Node {
+    File(String),
+    Dir,
+}
+
+thread_local! {
+    /// The read-only portfolio layer, built on first use.
+    static BASE: RefCell<Option<BTreeMap<String, Node>>> = const { RefCell::new(None) };
+    /// The visitor's layer, read from `localStorage` on first use.
+    static OVERLAY: RefCell<Option<BTreeMap<String, Node>>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug)]
+pub enum FsError {
+    NotFound,
+    PermissionDenied,
+    IsADirectory,
+    NotADirectory,
+    AlreadyExists,
+    /// Moving or copying a directory into itself.
+    InvalidArgument,
+    /// `localStorage` refused the write (disabled, or over quota).
+    Storage,
+}
+
+impl FsError {
+    fn reason(&self) -> &'static str {
+        match self {
+            FsError::NotFound => "No such file or directory",
+            FsError::PermissionDenied => "Permission denied",
+            FsError::IsADirectory => "Is a directory",
+            FsError::NotADirectory => "Not a directory",
+            FsError::AlreadyExists => "File exists",
+            FsError::InvalidArgument => "Invalid argument",
+            FsError::Storage => "Could not save (browser storage unavailable or full)",
+        }
+    }
+}
+
+/// `command: path: reason`, as an error line.
+pub fn error(command: &str, path: &str, err: FsError) -> String {
+    format!(
+        "<span style='color: #f44'>{}: {}: {}</span>",
+        command, browser::escape_html(&display(path)), err.reason()
+    )
+}
+
+/// Absolute, normalised form of `path` typed in directory `cwd`.
+pub fn resolve(cwd: &str, path: &str) -> String {
+    let full = if path == "~" || path.starts_with("~/") {
+        format!("{}{}", HOME, &path[1..])
+    } else if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("{}/{}", cwd, path)
+    };
+    let mut parts: Vec<&str> = Vec::new();
+    for part in full.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+/// `path` as the prompt would show it, with the home directory as `~`.
+pub fn display(path: &str) -> String {
+    match path.strip_prefix(HOME) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => format!("~{}", rest),
+        _ => path.to_string(),
+    }
+}
+
+fn parent(path: &str) -> &str {
+    match path.rfind('/') {
+        Some(0) => "/",
+        Some(i) => &path[..i],
+        None => "/",
+    }
+}
+
+/// Last component of `path`.
+pub fn name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or_default()
+}
+
+/// Whether `path` is `dir` or somewhere below it.
+fn within(path: &str, dir: &str) -> bool {
+    path == dir || dir == "/" || path.strip_prefix(dir).is_some_and(|rest| rest.starts_with('/'))
+}
+
+fn base() -> BTreeMap<String, Node> {
+    let mut base = BTreeMap::new();
+    for dir in ["/", "/home", HOME, SCRATCH] {
+        base.insert(dir.to_string(), Node::Dir);
+    }
+    base.insert(format!("{}/projects", HOME), Node::Dir);
+    for project in PROJECTS {
+        base.insert(format!("{}/projects/{}", HOME, project.id), Node::File(projects::project_markdown(project)));
+    }
+    base.insert(format!("{}/resume.md", HOME), Node::File(resume::render_markdown()));
+    base.insert(format!("{}/demo.sh", HOME), Node::File(script::DEMO.to_string()));
+    base.insert(
+        format!("{}/about.txt", HOME),
+        Node::File(format!("{}\n{}\n\n{}\n", PROFILE.name, PROFILE.role, PROFILE.summary)),
+    );
+    base
+}
+
+fn with_base<R>(f: impl FnOnce(&BTreeMap<String, Node>) -> R) -> R {
+    BASE.with(|b| f(b.borrow_mut().get_or_insert_with(base)))
+}
+
+fn in_base(path: &str) -> bool {
+    with_base(|base| base.contains_key(path))
+}
+
+fn with_overlay<R>(f: impl FnOnce(&mut BTreeMap<String, Node>) -> R) -> R {
+    OVERLAY.with(|o| f(o.borrow_mut().get_or_insert_with(load)))
+}
+
+/// Entries are stored as `{path: contents}`; directories as `{"path/": ""}`.
+fn load() -> BTreeMap<String, Node> {
+    let Some(json) = browser::load(STORAGE_KEY) else { return BTreeMap::new() };
+    let Ok(object) = js_sys::JSON::parse(&json) else { return BTreeMap::new() };
+    js_sys::Object::entries(&object.into())
+        .iter()
+        .filter_map(|entry| {
+            let pair = js_sys::Array::from(&entry);
+            let key = pair.get(0).as_string()?;
+            Some(match key.strip_suffix('/') {
+                Some(dir) => (dir.to_string(), Node::Dir),
+                None => (key, Node::File(pair.get(1).as_string()?)),
+            })
+        })
+        .collect()
+}
+
+fn save(overlay: &BTreeMap<String, Node>) -> Result<(), FsError> {
+    let object = js_sys::Object::new();
+    for (path, node) in overlay {
+        let (key, value) = match node {
+            Node::File(contents) => (path.clone(), contents.as_str()),
+            Node::Dir => (format!("{}/", path), ""),
+        };
+        js_sys::Reflect::set(&object, &JsValue::from_str(&key), &JsValue::from_str(value))
+            .map_err(|_| FsError::Storage)?;
+    }
+    let json = js_sys::JSON::stringify(&object).map_err(|_| FsError::Storage)?;
+    browser::store(STORAGE_KEY, &String::from(json)).map_err(|_| FsError::Storage)
+}
+
+/// Applies `change` to the visitor's layer and persists it; on any error
+/// the layer is left as it was.
+fn update<R>(change: impl FnOnce(&mut BTreeMap<String, Node>) -> Result<R, FsError>) -> Result<R, FsError> {
+    with_overlay(|overlay| {
+        let before = overlay.clone();
+        let result = change(overlay).and_then(|value| save(overlay).map(|()| value));
+        if result.is_err() {
+            *overlay = before;
+        }
+        result
+    })
+}
+
+fn node(path: &str) -> Option<Node> {
+    with_overlay(|overlay| overlay.get(path).cloned()).or_else(|| with_base(|base| base.get(path).cloned()))
+}
+
+pub fn exists(path: &str) -> bool {
+    node(path).is_some()
+}
+
+pub fn is_dir(path: &str) -> bool {
+    matches!(node(path), Some(Node::Dir))
+}
+
+/// Whether `path` is part of the portfolio itself rather than the visitor's.
+pub fn is_read_only(path: &str) -> bool {
+    in_base(path)
+}
+
+pub fn read(path: &str) -> Result<String, FsError> {
+    match node(path) {
+        Some(Node::File(contents)) => Ok(contents),
+        Some(Node::Dir) => Err(FsError::IsADirectory),
+        None => Err(FsError::NotFound),
+    }
+}
+
+/// Whether a new entry may be created at `path`.
+fn check_create(path: &str) -> Result<(), FsError> {
+    let dir = parent(path);
+    match node(dir) {
+        None => Err(FsError::NotFound),
+        Some(Node::File(_)) => Err(FsError::NotADirectory),
+        Some(Node::Dir) if in_base(dir) && !WRITABLE_DIRS.contains(&dir) => Err(FsError::PermissionDenied),
+        Some(Node::Dir) => Ok(()),
+    }
+}
+
+/// Whether the existing entry at `path` may be changed or removed.
+fn check_modify(path: &str) -> Result<(), FsError> {
+    if in_base(path) {
+        Err(FsError::PermissionDenied)
+    } else if exists(path) {
+        Ok(())
+    } else {
+        Err(FsError::NotFound)
+    }
+}
+
+/// Creates or replaces the file at `path`.
+pub fn write(path: &str, contents: &str) -> Result<(), FsError> {
+    match node(path) {
+        Some(Node::Dir) => return Err(FsError::IsADirectory),
+        Some(Node::File(_)) => check_modify(path)?,
+        None => check_create(path)?,
+    }
+    update(|overlay| {
+        overlay.insert(path.to_string(), Node::File(contents.to_string()));
+        Ok(())
+    })
+}
+
+/// Adds `text` to the end of the file at `path`, creating it if needed.
+pub fn append(path: &str, text: &str) -> Result<(), FsError> {
+    let existing = match read(path) {
+        Ok(contents) => contents,
+        Err(FsError::NotFound) => String::new(),
+        Err(err) => return Err(err),
+    };
+    write(path, &(existing + text))
+}
+
+/// Creates an empty file unless something is already at `path`.
+pub fn touch(path: &str) -> Result<(), FsError> {
+    if exists(path) {
+        return Ok(());
+    }
+    write(path, "")
+}
+
+/// Creates the directory `path`; with `parents`, any missing ones above it
+/// too, and an existing directory is not an error.
+pub fn mkdir(path: &str, parents: bool) -> Result<(), FsError> {
+    if exists(path) {
+        return if parents && is_dir(path) { Ok(()) } else { Err(FsError::AlreadyExists) };
+    }
+    if parents && !exists(parent(path)) {
+        mkdir(parent(path), true)?;
+    }
+    check_create(path)?;
+    update(|overlay| {
+        overlay.insert(path.to_string(), Node::Dir);
+        Ok(())
+    })
+}
+
+/// Paths in the visitor's layer at or below `path`.
+fn overlay_tree(path: &str) -> Vec<String> {
+    with_overlay(|overlay| overlay.keys().filter(|p| within(p, path)).cloned().collect())
+}
+
+/// Removes `path`; a directory only with `recursive`.
+pub fn remove(path: &str, recursive: bool) -> Result<(), FsError> {
+    check_modify(path)?;
+    if is_dir(path) && !recursive {
+        return Err(FsError::IsADirectory);
+    }
+    // Nothing read-only lives inside a visitor's directory, so the whole
+    // subtree is in their layer.
+    let doomed = overlay_tree(path);
+    update(|overlay| {
+        for p in &doomed {
+            overlay.remove(p);
+        }
+        Ok(())
+    })
+}
+
+/// Where `from` ends up when moved or copied to `to`: inside `to` if that
+/// is a directory.
+pub fn destination(from: &str, to: &str) -> String {
+    if is_dir(to) {
+        format!("{}/{}", to.trim_end_matches('/'), name(from))
+    } else {
+        to.to_string()
+    }
+}
+
+/// Copies `from` (a directory only with `recursive`) to `to`, replacing a
+/// file already there.
+pub fn copy(from: &str, to: &str, recursive: bool) -> Result<(), FsError> {
+    let to = destination(from, to);
+    match node(from) {
+        None => Err(FsError::NotFound),
+        Some(Node::File(contents)) => write(&to, &contents),
+        Some(Node::Dir) if !recursive => Err(FsError::IsADirectory),
+        Some(Node::Dir) if within(&to, from) => Err(FsError::InvalidArgument),
+        Some(Node::Dir) => {
+            if is_dir(&to) {
+                return Err(FsError::AlreadyExists);
+            }
+            mkdir(&to, false)?;
+            for child in children(from)? {
+                copy(&format!("{}/{}", from, child.name), &format!("{}/{}", to, child.name), true)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Renames `from` to `to` (or moves it into `to` if that is a directory).
+pub fn rename(from: &str, to: &str) -> Result<(), FsError> {
+    check_modify(from)?;
+    let to = destination(from, to);
+    if within(&to, from) {
+        return Err(FsError::InvalidArgument);
+    }
+    match node(&to) {
+        Some(Node::Dir) => return Err(FsError::IsADirectory),
+        Some(Node::File(_)) if is_dir(from) => return Err(FsError::NotADirectory),
+        Some(Node::File(_)) => check_modify(&to)?,
+        None => check_create(&to)?,
+    }
+    let moved = overlay_tree(from);
+    update(|overlay| {
+        for old in &moved {
+            if let Some(node) = overlay.remove(old) {
+                overlay.insert(format!("{}{}", to, &old[from.len()..]), node);
+            }
+        }
+        Ok(())
+    })
+}
+
+pub struct Entry {
+    pub name: String,
+    pub dir: bool,
+    /// Part of the read-only portfolio layer.
+    pub read_only: bool,
+}
+
+/// Entries in the directory at `path`, directories first, then by name.
+pub fn children(path: &str) -> Result<Vec<Entry>, FsError> {
+    match node(path) {
+        None => return Err(FsError::NotFound),
+        Some(Node::File(_)) => return Err(FsError::NotADirectory),
+        Some(Node::Dir) => {}
+    }
+    let mut entries: BTreeMap<String, Entry> = BTreeMap::new();
+    let mut add = |layer: &BTreeMap<String, Node>, read_only: bool| {
+        for (p, node) in layer {
+            if p != "/" && parent(p) == path {
+                let name = name(p).to_string();
+                entries.insert(name.clone(), Entry { name, dir: matches!(node, Node::Dir), read_only });
+            }
+        }
+    };
+    with_base(|base| add(base, true));
+    with_overlay(|overlay| add(overlay, false));
+    let mut entries: Vec<Entry> = entries.into_values().collect();
+    entries.sort_by_key(|e| !e.dir);
+    Ok(entries)
+}